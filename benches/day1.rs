@@ -0,0 +1,74 @@
+//! Compares the strategies for finding the first and last digit of a day 1 line.
+//!
+//! `part1/swar` only runs with `--features swar`, which `part1/solver` then uses as well.
+
+use advent_of_code::days::day1::{
+    find_number, find_number_boxed, find_number_enum, find_number_generic, find_number_ref, Day1,
+    DigitScanner, DigitVocabulary,
+};
+use advent_of_code::days::input::{load_if_present, InputSource};
+#[cfg(feature = "swar")]
+use advent_of_code::days::swar::sum_first_and_last_digits;
+use advent_of_code::days::Solver;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+const LINE: &str = "7pqrstsixteen";
+
+fn bench_find_number(c: &mut Criterion) {
+    let vocabulary = DigitVocabulary::english();
+    let mut group = c.benchmark_group("find_number");
+
+    group.bench_function("generic", |b| {
+        b.iter(|| find_number_generic(black_box(LINE), false))
+    });
+    group.bench_function("boxed", |b| {
+        b.iter(|| find_number_boxed(black_box(LINE), false))
+    });
+    group.bench_function("enum", |b| {
+        b.iter(|| find_number_enum(black_box(LINE), false))
+    });
+    group.bench_function("ref", |b| {
+        b.iter(|| find_number_ref(black_box(LINE), false))
+    });
+    group.bench_function("automaton", |b| {
+        b.iter(|| find_number(black_box(LINE), false, vocabulary))
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("find_number_reverse");
+
+    group.bench_function("generic", |b| {
+        b.iter(|| find_number_generic(black_box(LINE), true))
+    });
+    group.bench_function("automaton", |b| {
+        b.iter(|| find_number(black_box(LINE), true, vocabulary))
+    });
+    group.bench_function("automaton_single_pass", |b| {
+        b.iter(|| vocabulary.find_first_and_last(black_box(LINE)))
+    });
+    group.bench_function("scanner_single_pass", |b| {
+        b.iter(|| DigitScanner::new(black_box(LINE).chars(), vocabulary).first_and_last())
+    });
+    group.finish();
+}
+
+fn bench_part1(c: &mut Criterion) {
+    let input = load_if_present(&Day1, &InputSource::Puzzle).expect("puzzle input is readable");
+    let Some(input) = input else {
+        eprintln!("Skipping part1, no puzzle input for day 1");
+        return;
+    };
+    let parsed = Day1::parse(&input).expect("puzzle input parses");
+    let mut group = c.benchmark_group("part1");
+
+    group.bench_function("solver", |b| b.iter(|| Day1::part1(black_box(&parsed))));
+    #[cfg(feature = "swar")]
+    group.bench_function("swar", |b| {
+        b.iter(|| sum_first_and_last_digits(black_box(input.as_bytes())))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_find_number, bench_part1);
+criterion_main!(benches);
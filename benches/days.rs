@@ -0,0 +1,33 @@
+//! Parse, part 1 and part 2 of every registered day against the puzzle inputs.
+//!
+//! Save a baseline with `cargo bench --bench days -- --save-baseline main` and compare
+//! against it later with `cargo bench --bench days -- --baseline main`.
+
+use advent_of_code::days::input::{load_if_present, InputSource};
+use advent_of_code::days::DAYS;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+fn bench_days(c: &mut Criterion) {
+    for day in DAYS {
+        let input = load_if_present(*day, &InputSource::Puzzle).expect("puzzle input is readable");
+        let Some(input) = input else {
+            eprintln!("Skipping day {}, no puzzle input", day.number());
+            continue;
+        };
+        let parsed = day.parse(&input).expect("puzzle input parses");
+        let mut group = c.benchmark_group(format!("day{:02}", day.number()));
+
+        group.bench_function("parse", |b| b.iter(|| day.parse(black_box(&input))));
+        for part in 1..=day.parts() {
+            group.bench_function(format!("part{}", part), |b| {
+                b.iter(|| day.solve(black_box(parsed.as_ref()), part))
+            });
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
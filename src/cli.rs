@@ -0,0 +1,368 @@
+use advent_of_code::days::input::InputSource;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+const DEFAULT_TIME_REPEAT: usize = 10;
+const DEFAULT_JOBS_TIMEOUT: Duration = Duration::from_secs(60);
+
+pub const USAGE: &str = "Usage: advent_of_code [OPTIONS]
+       advent_of_code new-day <N> [--root <DIR>]
+
+Commands:
+  new-day <N>       Create the module, input files and registry entry for day N in the
+                    crate at --root (default: the current directory)
+
+Options:
+  --day <N>         Only run the given day
+  --part <N>        Only run the given part (1 or 2)
+  --all             Run every registered puzzle (default)
+  --input <PATH>    Read the input from PATH instead of inputs/dayNN.txt, - reads stdin
+  --example         Use inputs/dayNN.example.txt, or dayNN.example.partN.txt if present
+  --time            Print how long parsing and solving took
+  --repeat <N>      Run every phase N times for --time (default 10)
+  --verify          Compare the answers with inputs/answers.toml
+  --explain         Show how the answers are found, for days that support it
+  --format <FORMAT> Print the results as text (default), json or csv
+  --jobs <N>        Run up to N days at the same time (default 1)
+  --timeout <SECS>  Give up on a day after SECS seconds (default 60 with --jobs)
+  --list            List the registered puzzles
+  -h, --help        Print this help";
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Command {
+    Help,
+    List,
+    NewDay { day: u8, root: PathBuf },
+    Run(Run),
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Run {
+    pub selection: Selection,
+    pub input: InputSource,
+    pub time: bool,
+    pub verify: bool,
+    /// How often every phase is run, only the last result is reported.
+    pub repeat: usize,
+    /// Number of days that are run in parallel.
+    pub jobs: usize,
+    /// Time limit for each day, `None` waits forever.
+    pub timeout: Option<Duration>,
+    pub format: Format,
+    pub explain: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err("expected text, json or csv".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Selection {
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+}
+
+impl Selection {
+    pub fn matches(&self, day: u8, part: u8) -> bool {
+        self.day.is_none_or(|d| d == day) && self.part.is_none_or(|p| p == part)
+    }
+}
+
+impl Command {
+    pub fn parse<I>(args: I) -> Result<Command, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = args.into_iter().peekable();
+
+        if args.peek().is_some_and(|arg| arg == "new-day") {
+            args.next();
+            let day = parse_value("new-day", args.next())?;
+            if !(1..=25).contains(&day) {
+                return Err(format!("Day must be between 1 and 25, got: {}", day));
+            }
+            let mut root = PathBuf::from(".");
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--root" => root = parse_value("--root", args.next())?,
+                    _ => return Err(format!("Unknown argument: {}", arg)),
+                }
+            }
+            return Ok(Command::NewDay { day, root });
+        }
+        let mut selection = Selection::default();
+        let mut input = InputSource::default();
+        let mut all = false;
+        let mut list = false;
+        let mut time = false;
+        let mut verify = false;
+        let mut repeat: Option<usize> = None;
+        let mut jobs: Option<usize> = None;
+        let mut timeout: Option<Duration> = None;
+        let mut format = Format::default();
+        let mut explain = false;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(Command::Help),
+                "--all" => all = true,
+                "--list" => list = true,
+                "--example" => input = InputSource::Example,
+                "--time" => time = true,
+                "--verify" => verify = true,
+                "--explain" => explain = true,
+                "--repeat" => {
+                    let value = parse_value(&arg, args.next())?;
+                    if value == 0 {
+                        return Err("--repeat must be at least 1".to_string());
+                    }
+                    repeat = Some(value);
+                }
+                "--format" => format = parse_value(&arg, args.next())?,
+                "--jobs" => {
+                    let value = parse_value(&arg, args.next())?;
+                    if value == 0 {
+                        return Err("--jobs must be at least 1".to_string());
+                    }
+                    jobs = Some(value);
+                }
+                "--timeout" => {
+                    let value = parse_value(&arg, args.next())?;
+                    if value == 0 {
+                        return Err("--timeout must be at least 1".to_string());
+                    }
+                    timeout = Some(Duration::from_secs(value));
+                }
+                "--input" => {
+                    let value = args.next().ok_or("Missing value for --input")?;
+                    input = InputSource::from_arg(&value);
+                }
+                "--day" => selection.day = Some(parse_value(&arg, args.next())?),
+                "--part" => {
+                    let part = parse_value(&arg, args.next())?;
+                    if !(1..=2).contains(&part) {
+                        return Err(format!("Part must be 1 or 2, got: {}", part));
+                    }
+                    selection.part = Some(part);
+                }
+                _ => return Err(format!("Unknown argument: {}", arg)),
+            }
+        }
+
+        if all && (selection.day.is_some() || selection.part.is_some()) {
+            return Err("--all can not be combined with --day or --part".to_string());
+        }
+
+        if selection.day.is_none() && matches!(input, InputSource::Path(_) | InputSource::Stdin) {
+            return Err("--input requires --day".to_string());
+        }
+
+        if verify && input != InputSource::Puzzle {
+            return Err("--verify only works with the puzzle inputs".to_string());
+        }
+
+        if explain && (time || verify || format != Format::Text) {
+            return Err(
+                "--explain can not be combined with --time, --verify or --format".to_string(),
+            );
+        }
+
+        if repeat.is_some() && !time {
+            return Err("--repeat requires --time".to_string());
+        }
+
+        if list {
+            return Ok(Command::List);
+        }
+
+        let repeat = match (time, repeat) {
+            (true, None) => DEFAULT_TIME_REPEAT,
+            (_, repeat) => repeat.unwrap_or(1),
+        };
+
+        let timeout = match (jobs, timeout) {
+            (Some(_), None) => Some(DEFAULT_JOBS_TIMEOUT),
+            (_, timeout) => timeout,
+        };
+
+        Ok(Command::Run(Run {
+            selection,
+            input,
+            time,
+            verify,
+            repeat,
+            jobs: jobs.unwrap_or(1),
+            timeout,
+            format,
+            explain,
+        }))
+    }
+}
+
+fn parse_value<T>(flag: &str, value: Option<String>) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let value = value.ok_or(format!("Missing value for {}", flag))?;
+
+    value.parse::<T>().map_err(|err| {
+        format!(
+            "Could not parse value for {}: {}. Got error: {}",
+            flag, value, err
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        Command::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test_case(&[], Selection { day: None, part: None })]
+    #[test_case(&["--all"], Selection { day: None, part: None })]
+    #[test_case(&["--day", "2"], Selection { day: Some(2), part: None })]
+    #[test_case(&["--day", "2", "--part", "1"], Selection { day: Some(2), part: Some(1) })]
+    #[test_case(&["--part", "2"], Selection { day: None, part: Some(2) })]
+    fn it_parses_selection(args: &[&str], expected: Selection) {
+        let expected = Run {
+            selection: expected,
+            input: InputSource::Puzzle,
+            time: false,
+            verify: false,
+            repeat: 1,
+            jobs: 1,
+            timeout: None,
+            format: Format::Text,
+            explain: false,
+        };
+
+        assert_eq!(Ok(Command::Run(expected)), parse(args));
+    }
+
+    #[test_case(&["--example"], InputSource::Example)]
+    #[test_case(&["--day", "1", "--input", "-"], InputSource::Stdin)]
+    #[test_case(&["--day", "1", "--input", "in.txt"], InputSource::Path("in.txt".into()))]
+    fn it_parses_input_source(args: &[&str], expected: InputSource) {
+        match parse(args) {
+            Ok(Command::Run(run)) => assert_eq!(expected, run.input),
+            other => panic!("Expected run command, got: {:?}", other),
+        }
+    }
+
+    #[test_case(&["--time"], 10)]
+    #[test_case(&["--time", "--repeat", "3"], 3)]
+    #[test_case(&["--repeat", "300", "--time"], 300)]
+    fn it_parses_time(args: &[&str], expected_repeat: usize) {
+        match parse(args) {
+            Ok(Command::Run(run)) => {
+                assert!(run.time);
+                assert_eq!(expected_repeat, run.repeat);
+            }
+            other => panic!("Expected run command, got: {:?}", other),
+        }
+    }
+
+    #[test_case(&["--all", "--jobs", "4"], 4, Some(60))]
+    #[test_case(&["--jobs", "2", "--timeout", "5"], 2, Some(5))]
+    #[test_case(&["--timeout", "5"], 1, Some(5))]
+    #[test_case(&[], 1, None)]
+    fn it_parses_jobs(args: &[&str], expected_jobs: usize, expected_timeout: Option<u64>) {
+        match parse(args) {
+            Ok(Command::Run(run)) => {
+                assert_eq!(expected_jobs, run.jobs);
+                assert_eq!(expected_timeout.map(Duration::from_secs), run.timeout);
+            }
+            other => panic!("Expected run command, got: {:?}", other),
+        }
+    }
+
+    #[test_case(&["--format", "text"], Format::Text)]
+    #[test_case(&["--format", "json"], Format::Json)]
+    #[test_case(&["--all", "--format", "csv"], Format::Csv)]
+    fn it_parses_format(args: &[&str], expected: Format) {
+        match parse(args) {
+            Ok(Command::Run(run)) => assert_eq!(expected, run.format),
+            other => panic!("Expected run command, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_parses_explain() {
+        match parse(&["--day", "1", "--explain", "--example"]) {
+            Ok(Command::Run(run)) => assert!(run.explain),
+            other => panic!("Expected run command, got: {:?}", other),
+        }
+    }
+
+    #[test_case(&["new-day", "3"], ".")]
+    #[test_case(&["new-day", "3", "--root", "../aoc"], "../aoc")]
+    fn it_parses_new_day(args: &[&str], root: &str) {
+        let root = PathBuf::from(root);
+
+        assert_eq!(Ok(Command::NewDay { day: 3, root }), parse(args));
+    }
+
+    #[test]
+    fn it_parses_list() {
+        assert_eq!(Ok(Command::List), parse(&["--list"]));
+    }
+
+    #[test_case(&["--day"])]
+    #[test_case(&["--day", "x"])]
+    #[test_case(&["--part", "3"])]
+    #[test_case(&["--all", "--day", "1"])]
+    #[test_case(&["--foo"])]
+    #[test_case(&["--input"])]
+    #[test_case(&["--input", "in.txt"])]
+    #[test_case(&["--repeat", "2"])]
+    #[test_case(&["--verify", "--example"])]
+    #[test_case(&["new-day"])]
+    #[test_case(&["new-day", "26"])]
+    #[test_case(&["new-day", "3", "--day", "3"])]
+    #[test_case(&["new-day", "3", "--root"])]
+    #[test_case(&["--time", "--repeat", "0"])]
+    #[test_case(&["--jobs", "0"])]
+    #[test_case(&["--format", "xml"])]
+    #[test_case(&["--explain", "--time"])]
+    #[test_case(&["--explain", "--format", "json"])]
+    #[test_case(&["--timeout", "0"])]
+    fn it_rejects_invalid_arguments(args: &[&str]) {
+        assert!(parse(args).is_err());
+    }
+
+    #[test_case(1, 1, true)]
+    #[test_case(1, 2, false)]
+    #[test_case(2, 1, false)]
+    fn selection_matches(day: u8, part: u8, expected: bool) {
+        let selection = Selection {
+            day: Some(1),
+            part: Some(1),
+        };
+
+        assert_eq!(expected, selection.matches(day, part));
+    }
+}
@@ -0,0 +1,233 @@
+use crate::days::input::resolve;
+use crate::days::Answer;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Accepted answers, read from a TOML file of the form
+///
+/// ```toml
+/// [day01]
+/// part1 = 54968
+/// part2 = "text answer"
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), Answer>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } => write!(f, "FAIL, expected {}", expected),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn path() -> PathBuf {
+        resolve(ANSWERS_FILE)
+    }
+
+    /// Reads [`Answers::path`], a missing file means that no answer is known yet.
+    pub fn load() -> Result<Answers, String> {
+        let path = Answers::path();
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .map_err(|err| format!("could not read {}: {}", path.display(), err))?;
+
+        Answers::parse(&content).map_err(|err| format!("{}: {}", path.display(), err))
+    }
+
+    /// Understands the subset of TOML the answers file needs: `[dayNN]` tables with
+    /// `partN` keys holding integers or basic strings with the usual escapes, and `#` comments.
+    pub fn parse(input: &str) -> Result<Answers, String> {
+        let mut answers = Answers::default();
+        let mut day: Option<u8> = None;
+
+        for (index, line) in input.lines().enumerate() {
+            let error = |reason: &str| format!("line {}: {} in {:?}", index + 1, reason, line);
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(table) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let number = table
+                    .trim()
+                    .strip_prefix("day")
+                    .and_then(|number| number.parse::<u8>().ok())
+                    .ok_or_else(|| error("expected [dayNN]"))?;
+                day = Some(number);
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected partN = <answer>"))?;
+            let part = key
+                .trim()
+                .strip_prefix("part")
+                .and_then(|number| number.parse::<u8>().ok())
+                .ok_or_else(|| error("expected partN as key"))?;
+            let day = day.ok_or_else(|| error("answer outside of a [dayNN] table"))?;
+
+            answers.answers.insert(
+                (day, part),
+                parse_value(value).ok_or_else(|| error("invalid value"))?,
+            );
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u8, u8, &Answer)> {
+        self.answers
+            .iter()
+            .map(|((day, part), answer)| (*day, *part, answer))
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+/// An integer or a basic string, either may be followed by a comment. A `#` inside the string
+/// belongs to the answer.
+fn parse_value(value: &str) -> Option<Answer> {
+    let value = value.trim();
+
+    if let Some(quoted) = value.strip_prefix('"') {
+        let mut text = String::new();
+        let mut chars = quoted.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => return only_comment(chars.as_str()).then_some(Answer::Text(text)),
+                '\\' => text.push(match chars.next()? {
+                    'n' => '\n',
+                    't' => '\t',
+                    c @ ('"' | '\\') => c,
+                    _ => return None,
+                }),
+                c => text.push(c),
+            }
+        }
+        return None;
+    }
+
+    let value = value
+        .split_once('#')
+        .map_or(value, |(value, _comment)| value);
+    value
+        .trim()
+        .replace('_', "")
+        .parse::<u64>()
+        .ok()
+        .map(Answer::Number)
+}
+
+fn only_comment(rest: &str) -> bool {
+    let rest = rest.trim();
+    rest.is_empty() || rest.starts_with('#')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::find_day;
+    use crate::days::input::puzzle_input;
+
+    #[test]
+    fn it_parses_answers() {
+        let answers = Answers::parse(
+            "# comment\n[day01]\npart1 = 54_968 # accepted\n\n[day12]\npart2 = \"ABC\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(Some(&Answer::Number(54968)), answers.get(1, 1));
+        assert_eq!(Some(&Answer::Text("ABC".to_string())), answers.get(12, 2));
+        assert_eq!(None, answers.get(1, 2));
+    }
+
+    #[test]
+    fn it_keeps_hashes_inside_strings() {
+        let answers = Answers::parse(
+            "[day01]\npart1 = \"a # b\" # comment\npart2 = \"say \\\"hi\\\" # \\\\\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(Some(&Answer::Text("a # b".to_string())), answers.get(1, 1));
+        assert_eq!(
+            Some(&Answer::Text("say \"hi\" # \\".to_string())),
+            answers.get(1, 2)
+        );
+    }
+
+    #[test]
+    fn it_rejects_invalid_answers() {
+        assert!(Answers::parse("part1 = 1").is_err());
+        assert!(Answers::parse("[day01]\npart1 = abc").is_err());
+        assert!(Answers::parse("[first]\npart1 = 1").is_err());
+        assert!(Answers::parse("[day01]\nfoo = 1").is_err());
+        assert!(Answers::parse("[day01]\npart1 = \"open # comment").is_err());
+        assert!(Answers::parse("[day01]\npart1 = \"a\" b").is_err());
+    }
+
+    #[test]
+    fn it_checks_answers() {
+        let answers = Answers::parse("[day01]\npart1 = 12").unwrap();
+
+        assert_eq!(Verdict::Pass, answers.check(1, 1, &Answer::Number(12)));
+        assert_eq!(
+            Verdict::Fail {
+                expected: Answer::Number(12)
+            },
+            answers.check(1, 1, &Answer::Number(13))
+        );
+        assert_eq!(Verdict::Unknown, answers.check(1, 2, &Answer::Number(12)));
+    }
+
+    #[test]
+    fn recorded_answers_are_still_produced() {
+        let answers = Answers::load().unwrap();
+
+        for (day, part, expected) in answers.iter() {
+            let day = find_day(day).expect("answer for a registered day");
+            let Some(input) = puzzle_input(day) else {
+                continue;
+            };
+
+            assert_eq!(
+                *expected,
+                day.run(&input, part).unwrap(),
+                "day {} part {}",
+                day.number(),
+                part
+            );
+        }
+    }
+}
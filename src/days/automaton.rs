@@ -0,0 +1,364 @@
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+
+const ROOT: usize = 0;
+const ALPHABET: usize = 256;
+
+/// Multi pattern matcher (Aho-Corasick) over the bytes of a line. Every pattern carries the
+/// value that is reported when it matches.
+///
+/// The automaton is compiled twice, once for the patterns and once for the reversed patterns,
+/// so the last match of a line can be found by scanning from its end.
+#[derive(Debug, Clone)]
+pub struct Automaton {
+    forward: Dfa,
+    reverse: Dfa,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Match {
+    pub value: u32,
+    /// Byte offset of the first byte of the match.
+    pub start: usize,
+    /// Byte offset after the last byte of the match.
+    pub end: usize,
+}
+
+/// Scans a haystack that is read piecewise, one byte at a time, see [`Automaton::cursor`].
+#[derive(Debug, Clone)]
+pub struct Cursor<'a> {
+    dfa: &'a Dfa,
+    state: usize,
+}
+
+/// Dense automaton where every state has a transition for every byte, so scanning never has
+/// to follow failure links.
+#[derive(Debug, Clone)]
+struct Dfa {
+    transitions: Vec<usize>,
+    /// Patterns ending in each state as `(value, length)`, longest first.
+    outputs: Vec<Vec<(u32, usize)>>,
+    /// Length of the pattern prefix each state stands for. A match that is yet to be completed
+    /// started within that many of the last bytes.
+    depths: Vec<usize>,
+}
+
+impl Automaton {
+    pub fn new<'a, I>(patterns: I) -> Automaton
+    where
+        I: IntoIterator<Item = (&'a str, u32)>,
+    {
+        let patterns: Vec<(&[u8], u32)> = patterns
+            .into_iter()
+            .filter(|(pattern, _)| !pattern.is_empty())
+            .map(|(pattern, value)| (pattern.as_bytes(), value))
+            .collect();
+        let reversed: Vec<(Vec<u8>, u32)> = patterns
+            .iter()
+            .map(|(pattern, value)| (pattern.iter().rev().copied().collect(), *value))
+            .collect();
+
+        Automaton {
+            forward: Dfa::new(patterns.iter().map(|(pattern, value)| (*pattern, *value))),
+            reverse: Dfa::new(
+                reversed
+                    .iter()
+                    .map(|(pattern, value)| (pattern.as_slice(), *value)),
+            ),
+        }
+    }
+
+    /// The match that starts first. If several matches start at the same byte the longest wins.
+    pub fn find_first(&self, haystack: &str) -> Option<Match> {
+        let mut state = ROOT;
+        let mut first: Option<Match> = None;
+
+        for (index, byte) in haystack.bytes().enumerate() {
+            state = self.forward.next(state, byte);
+            // Shorter matches ending here start later than the longest one.
+            if let Some(&(value, len)) = self.forward.outputs[state].first() {
+                let start = index + 1 - len;
+                if first.is_none_or(|curr| start <= curr.start) {
+                    first = Some(Match {
+                        value,
+                        start,
+                        end: index + 1,
+                    });
+                }
+            }
+            if first.is_some_and(|first| first.start < index + 1 - self.forward.depths[state]) {
+                break;
+            }
+        }
+
+        first
+    }
+
+    /// The match that starts last. If several matches start at the same byte the longest wins.
+    pub fn find_last(&self, haystack: &str) -> Option<Match> {
+        let mut state = ROOT;
+
+        for (index, byte) in haystack.bytes().enumerate().rev() {
+            state = self.reverse.next(state, byte);
+            if let Some(&(value, len)) = self.reverse.outputs[state].first() {
+                return Some(Match {
+                    value,
+                    start: index,
+                    end: index + len,
+                });
+            }
+        }
+
+        None
+    }
+
+    /// A cursor at the start of a haystack. With `reverse` it is at the end instead and the
+    /// bytes have to be pushed from the last one backwards.
+    pub fn cursor(&self, reverse: bool) -> Cursor<'_> {
+        let dfa = if reverse {
+            &self.reverse
+        } else {
+            &self.forward
+        };
+
+        Cursor { dfa, state: ROOT }
+    }
+
+    /// Every match, also those that overlap, ordered by start. Of several matches starting at the
+    /// same byte the longest comes first.
+    pub fn find_all(&self, haystack: &str) -> Vec<Match> {
+        let mut state = ROOT;
+        let mut matches = Vec::new();
+
+        for (index, byte) in haystack.bytes().enumerate() {
+            state = self.forward.next(state, byte);
+            matches.extend(
+                self.forward.outputs[state]
+                    .iter()
+                    .map(|&(value, len)| Match {
+                        value,
+                        start: index + 1 - len,
+                        end: index + 1,
+                    }),
+            );
+        }
+
+        matches.sort_by_key(|found| (found.start, Reverse(found.end)));
+        matches
+    }
+
+    /// First and last match as defined by [`Automaton::find_first`] and
+    /// [`Automaton::find_last`], found in a single scan over the whole haystack.
+    pub fn find_first_and_last(&self, haystack: &str) -> Option<(Match, Match)> {
+        let mut state = ROOT;
+        let mut first: Option<Match> = None;
+        let mut last: Option<Match> = None;
+
+        for (index, byte) in haystack.bytes().enumerate() {
+            state = self.forward.next(state, byte);
+
+            // Shorter matches ending here start later than the longest one.
+            for &(value, len) in self.forward.outputs[state].iter() {
+                let found = Match {
+                    value,
+                    start: index + 1 - len,
+                    end: index + 1,
+                };
+                if first.is_none_or(|curr| {
+                    (found.start, Reverse(found.end)) < (curr.start, Reverse(curr.end))
+                }) {
+                    first = Some(found);
+                }
+                if last.is_none_or(|curr| (found.start, found.end) > (curr.start, curr.end)) {
+                    last = Some(found);
+                }
+            }
+        }
+
+        first.zip(last)
+    }
+}
+
+impl Cursor<'_> {
+    /// Reads the next byte and returns value and length of the longest pattern it completes.
+    pub fn push(&mut self, byte: u8) -> Option<(u32, usize)> {
+        self.state = self.dfa.next(self.state, byte);
+
+        self.dfa.outputs[self.state].first().copied()
+    }
+
+    /// Number of the last bytes that may still become part of a match, every match completed
+    /// later starts within them.
+    pub fn depth(&self) -> usize {
+        self.dfa.depths[self.state]
+    }
+}
+
+impl Dfa {
+    fn new<'a, I>(patterns: I) -> Dfa
+    where
+        I: Iterator<Item = (&'a [u8], u32)>,
+    {
+        let mut goto: Vec<[Option<usize>; ALPHABET]> = vec![[None; ALPHABET]];
+        let mut outputs: Vec<Vec<(u32, usize)>> = vec![Vec::new()];
+        let mut depths = vec![0];
+
+        for (pattern, value) in patterns {
+            let mut state = ROOT;
+            for byte in pattern {
+                state = match goto[state][*byte as usize] {
+                    Some(next) => next,
+                    None => {
+                        goto.push([None; ALPHABET]);
+                        outputs.push(Vec::new());
+                        depths.push(depths[state] + 1);
+                        let next = goto.len() - 1;
+                        goto[state][*byte as usize] = Some(next);
+                        next
+                    }
+                };
+            }
+            // The first pattern wins if the same pattern is given twice.
+            if outputs[state].is_empty() {
+                outputs[state].push((value, pattern.len()));
+            }
+        }
+
+        let mut transitions = vec![ROOT; goto.len() * ALPHABET];
+        let mut fail = vec![ROOT; goto.len()];
+        let mut queue = VecDeque::new();
+
+        for byte in 0..ALPHABET {
+            if let Some(next) = goto[ROOT][byte] {
+                transitions[byte] = next;
+                queue.push_back(next);
+            }
+        }
+
+        // Breadth first, so the failure state of every state is complete before it is used.
+        while let Some(state) = queue.pop_front() {
+            // Every pattern of the failure state is a shorter suffix of this state.
+            let suffixes = outputs[fail[state]].clone();
+            outputs[state].extend(suffixes);
+
+            for byte in 0..ALPHABET {
+                let fallback = transitions[fail[state] * ALPHABET + byte];
+                match goto[state][byte] {
+                    Some(next) => {
+                        fail[next] = fallback;
+                        transitions[state * ALPHABET + byte] = next;
+                        queue.push_back(next);
+                    }
+                    None => transitions[state * ALPHABET + byte] = fallback,
+                }
+            }
+        }
+
+        Dfa {
+            transitions,
+            outputs,
+            depths,
+        }
+    }
+
+    fn next(&self, state: usize, byte: u8) -> usize {
+        self.transitions[state * ALPHABET + byte as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const WORDS: [(&str, u32); 4] = [("one", 1), ("two", 2), ("eight", 8), ("1", 1)];
+
+    fn at(value: u32, start: usize, end: usize) -> Match {
+        Match { value, start, end }
+    }
+
+    #[test_case("twone", Some(at(2, 0, 3)), Some(at(1, 2, 5)))]
+    #[test_case("eightwo", Some(at(8, 0, 5)), Some(at(2, 4, 7)))]
+    #[test_case("xx1xone", Some(at(1, 2, 3)), Some(at(1, 4, 7)))]
+    #[test_case("ttwoo", Some(at(2, 1, 4)), Some(at(2, 1, 4)))]
+    #[test_case("abc", None, None)]
+    #[test_case("", None, None)]
+    fn it_finds_first_and_last(haystack: &str, first: Option<Match>, last: Option<Match>) {
+        let automaton = Automaton::new(WORDS);
+
+        assert_eq!(first, automaton.find_first(haystack));
+        assert_eq!(last, automaton.find_last(haystack));
+        assert_eq!(first.zip(last), automaton.find_first_and_last(haystack));
+    }
+
+    #[test_case("seventeen", at(17, 0, 9), at(10, 5, 9))]
+    #[test_case("seventeex", at(7, 0, 5), at(7, 0, 5))]
+    #[test_case("xsevenine", at(7, 1, 6), at(9, 5, 9))]
+    fn it_prefers_the_longest_of_matches_starting_together(
+        haystack: &str,
+        first: Match,
+        last: Match,
+    ) {
+        let automaton =
+            Automaton::new([("seven", 7), ("seventeen", 17), ("teen", 10), ("nine", 9)]);
+
+        assert_eq!(Some(first), automaton.find_first(haystack));
+        assert_eq!(Some(last), automaton.find_last(haystack));
+        assert_eq!(Some((first, last)), automaton.find_first_and_last(haystack));
+        assert_eq!(Some(&first), automaton.find_all(haystack).first());
+    }
+
+    #[test_case("ushers", at(1, 1, 4), at(3, 2, 6))]
+    #[test_case("hishe", at(4, 0, 3), at(2, 3, 5))]
+    fn it_reports_patterns_that_are_suffixes_of_others(haystack: &str, first: Match, last: Match) {
+        let automaton = Automaton::new([("he", 2), ("she", 1), ("hers", 3), ("his", 4)]);
+
+        assert_eq!(Some(first), automaton.find_first(haystack));
+        assert_eq!(Some(last), automaton.find_last(haystack));
+        assert_eq!(Some((first, last)), automaton.find_first_and_last(haystack));
+    }
+
+    #[test_case("twone", vec![at(2, 0, 3), at(1, 2, 5)])]
+    #[test_case("ushers", vec![at(1, 1, 4), at(3, 2, 6), at(2, 2, 4)])]
+    #[test_case("abc", vec![])]
+    fn it_finds_all_matches(haystack: &str, expected: Vec<Match>) {
+        let automaton =
+            Automaton::new(
+                WORDS
+                    .into_iter()
+                    .chain([("he", 2), ("she", 1), ("hers", 3)]),
+            );
+
+        assert_eq!(expected, automaton.find_all(haystack));
+    }
+
+    #[test]
+    fn cursors_agree_with_find_first_and_last() {
+        let automaton = Automaton::new(WORDS);
+        let mut forward = automaton.cursor(false);
+        let mut reverse = automaton.cursor(true);
+
+        assert_eq!(
+            Some((2, 3)),
+            "xtwone".bytes().find_map(|byte| forward.push(byte))
+        );
+        // A match completed later may start in "two".
+        assert_eq!(3, forward.depth());
+        assert_eq!(
+            Some((1, 3)),
+            "xtwone".bytes().rev().find_map(|byte| reverse.push(byte))
+        );
+        // The cursor keeps its state, "ne" completes the "one" that started with the "o".
+        assert_eq!(
+            Some((1, 3)),
+            "ne".bytes().find_map(|byte| forward.push(byte))
+        );
+    }
+
+    #[test]
+    fn it_skips_multi_byte_characters() {
+        let automaton = Automaton::new(WORDS);
+
+        assert_eq!(Some(at(2, 4, 7)), automaton.find_first("äőtwo"));
+    }
+}
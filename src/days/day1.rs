@@ -1,335 +1,789 @@
-use crate::days::input::PUZZLE_1_INPUT;
+use crate::days::automaton::{Automaton, Cursor, Match as Token};
+use crate::days::error::{Error, ParseError};
+#[cfg(feature = "swar")]
+use crate::days::swar;
+use crate::days::total::Total;
+use crate::days::{Answer, Example, Solver};
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
 use std::iter::Rev;
+use std::ops::Range;
 use std::str::Chars;
+use std::sync::OnceLock;
+
+const DIGITS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+
+const ENGLISH_WORDS: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    const DAY: u8 = 1;
+    #[cfg(feature = "embedded-inputs")]
+    const EMBEDDED_INPUT: Option<&'static str> = Some(include_str!("../../inputs/day01.txt"));
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: include_str!("../../inputs/day01.example.txt"),
+            expected: "142",
+        },
+        Example {
+            part: 2,
+            input: include_str!("../../inputs/day01.example.part2.txt"),
+            expected: "281",
+        },
+    ];
+
+    /// The whole input, so that part 1 can scan it at once.
+    type Parsed = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Ok(input.to_string())
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Answer, Error> {
+        // The fast path gives up on bad lines, the scalar one reports where they are.
+        #[cfg(feature = "swar")]
+        if let Some(sum) = swar::sum_first_and_last_digits(parsed.as_bytes()) {
+            return Ok(sum.into());
+        }
+
+        Ok(
+            Day1::part1_with::<u64>(parsed, InvalidLinePolicy::FailFast)?
+                .sum
+                .into(),
+        )
+    }
 
-pub fn puzzle1() -> Result<u32, &'static str> {
-    PUZZLE_1_INPUT
-        .lines()
-        .map(extract_numbers_from_line)
-        .try_fold(0, |acc, curr| Ok(acc + curr?))
+    fn part2(parsed: &Self::Parsed) -> Result<Answer, Error> {
+        Ok(
+            Day1::part2_with::<u64>(parsed, InvalidLinePolicy::FailFast)?
+                .sum
+                .into(),
+        )
+    }
+
+    fn explain(parsed: &Self::Parsed) -> Option<String> {
+        Some(explain(parsed))
+    }
 }
 
-fn extract_numbers_from_line(line: &str) -> Result<u32, &'static str> {
-    let first_index = line
-        .find(char::is_numeric)
-        .ok_or("Bad input. Could not find first number")?;
-    let last_index = line
-        .rfind(char::is_numeric)
-        .ok_or("Bad input. Could not find last number")?;
+impl Day1 {
+    /// Part 1 summed up in `T`.
+    pub fn part1_with<T: Total>(
+        input: &str,
+        policy: InvalidLinePolicy,
+    ) -> Result<Calibration<T>, Error> {
+        sum_lines(input, policy, |line| {
+            extract_numbers_from_line(line, DigitPolicy::Ascii)
+        })
+    }
 
-    let first = line.chars().nth(first_index).unwrap().to_digit(10).unwrap();
-    let last = line.chars().nth(last_index).unwrap().to_digit(10).unwrap();
+    /// Part 2 summed up in `T`.
+    pub fn part2_with<T: Total>(
+        input: &str,
+        policy: InvalidLinePolicy,
+    ) -> Result<Calibration<T>, Error> {
+        let vocabulary = DigitVocabulary::english();
 
-    Ok(first * 10 + last)
+        sum_lines(input, policy, |line| {
+            extract_numbers_and_spelled_numbers_from_line(line, vocabulary)
+        })
+    }
+
+    /// Part 1 without reading the whole input into memory, only the current line is kept.
+    pub fn part1_streaming<T: Total, R: BufRead>(
+        reader: R,
+        policy: InvalidLinePolicy,
+    ) -> Result<Calibration<T>, Error> {
+        sum_reader(reader, policy, |line| {
+            extract_numbers_from_line(line, DigitPolicy::Ascii)
+        })
+    }
+
+    /// Part 2 without reading the whole input into memory, only the current line is kept.
+    pub fn part2_streaming<T: Total, R: BufRead>(
+        reader: R,
+        policy: InvalidLinePolicy,
+    ) -> Result<Calibration<T>, Error> {
+        let vocabulary = DigitVocabulary::english();
+
+        sum_reader(reader, policy, |line| {
+            extract_numbers_and_spelled_numbers_from_line(line, vocabulary)
+        })
+    }
 }
 
-#[derive(Debug, Clone)]
-struct Match {
-    value: &'static str,
-    number_value: u32,
-    current_index: usize,
-}
-
-impl Match {
-    const ALL_MATCHES: [Match; 9] = [
-        Match {
-            current_index: 0,
-            value: "one",
-            number_value: 1,
-        },
-        Match {
-            current_index: 0,
-            value: "two",
-            number_value: 2,
-        },
-        Match {
-            current_index: 0,
-            value: "three",
-            number_value: 3,
-        },
-        Match {
-            current_index: 0,
-            value: "four",
-            number_value: 4,
-        },
-        Match {
-            current_index: 0,
-            value: "five",
-            number_value: 5,
-        },
-        Match {
-            current_index: 0,
-            value: "six",
-            number_value: 6,
-        },
-        Match {
-            current_index: 0,
-            value: "seven",
-            number_value: 7,
-        },
-        Match {
-            current_index: 0,
-            value: "eight",
-            number_value: 8,
-        },
-        Match {
-            current_index: 0,
-            value: "nine",
-            number_value: 9,
-        },
-    ];
+/// What happens to lines without a calibration value.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum InvalidLinePolicy {
+    /// Stop at the first one, as the puzzle expects.
+    #[default]
+    FailFast,
+    /// Leave the line out and report it.
+    Skip,
+    /// Count the line with a value of zero and report it.
+    Zero,
+}
 
-    fn spawn_into(value: char, buf: &mut Vec<Match>, reverse: bool) {
-        for example_match in Match::ALL_MATCHES {
-            let char_value = if reverse {
-                example_match.value.chars().rev().nth(0)
-            } else {
-                example_match.value.chars().nth(0)
-            };
+/// Sum of the calibration values of a document.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Calibration<T> {
+    pub sum: T,
+    /// Number of lines in the sum, including those counted as zero.
+    pub lines: usize,
+    /// One error for every invalid line that was skipped or counted as zero.
+    pub diagnostics: Vec<ParseError>,
+}
 
-            if char_value.unwrap() == value {
-                buf.push(example_match.clone())
+impl<T: Total> Calibration<T> {
+    fn add(
+        &mut self,
+        line: usize,
+        value: Result<u32, ParseError>,
+        policy: InvalidLinePolicy,
+    ) -> Result<(), Error> {
+        match (value.map_err(|err| err.at_line(line)), policy) {
+            (Ok(value), _) => {
+                self.sum = self.sum.checked_add(value.into()).ok_or(Error::Overflow {
+                    day: Day1::DAY,
+                    line: Some(line),
+                    total: T::name(),
+                })?;
+                self.lines += 1;
+            }
+            (Err(err), InvalidLinePolicy::FailFast) => return Err(err.into()),
+            (Err(err), InvalidLinePolicy::Skip) => self.diagnostics.push(err),
+            (Err(err), InvalidLinePolicy::Zero) => {
+                self.lines += 1;
+                self.diagnostics.push(err);
             }
         }
+
+        Ok(())
+    }
+}
+
+/// Same as [`sum_lines`] for a reader, reusing one buffer for every line.
+fn sum_reader<T, R, F>(
+    mut reader: R,
+    policy: InvalidLinePolicy,
+    extract: F,
+) -> Result<Calibration<T>, Error>
+where
+    T: Total,
+    R: BufRead,
+    F: Fn(&str) -> Result<u32, ParseError>,
+{
+    let mut buffer = Vec::new();
+    let mut calibration = Calibration::default();
+
+    for number in 1.. {
+        buffer.clear();
+        let read = reader
+            .read_until(b'\n', &mut buffer)
+            .map_err(|source| Error::Io {
+                day: Day1::DAY,
+                path: None,
+                source,
+            })?;
+        if read == 0 {
+            break;
+        }
+
+        // Line endings are the same as for `str::lines`.
+        let line = buffer.strip_suffix(b"\n").unwrap_or(&buffer);
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        let value = std::str::from_utf8(line)
+            .map_err(|err| {
+                ParseError::new(Day1::DAY, String::from_utf8_lossy(line), "invalid UTF-8")
+                    .at_column(err.valid_up_to() + 1)
+            })
+            .and_then(&extract);
+
+        calibration.add(number, value, policy)?;
     }
 
-    fn advance(mut self, value: char, reverse: bool) -> Option<Self> {
-        self.current_index += 1;
+    Ok(calibration)
+}
 
-        let char_at_index = if reverse {
-            self.value.chars().rev().nth(self.current_index)
-        } else {
-            self.value.chars().nth(self.current_index)
-        };
+fn sum_lines<T, F>(
+    input: &str,
+    policy: InvalidLinePolicy,
+    extract: F,
+) -> Result<Calibration<T>, Error>
+where
+    T: Total,
+    F: Fn(&str) -> Result<u32, ParseError>,
+{
+    let mut calibration = Calibration::default();
 
-        match char_at_index {
-            None => None,
-            Some(c) => {
-                if c == value {
-                    return Some(self);
-                }
-                None
+    for (index, line) in input.lines().enumerate() {
+        calibration.add(index + 1, extract(line), policy)?;
+    }
+
+    Ok(calibration)
+}
+
+/// Which characters count as digits. Numeric characters that are not decimal digits, like
+/// '½' or 'Ⅻ', never do.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum DigitPolicy {
+    /// Only `0` to `9`.
+    #[default]
+    Ascii,
+    /// Every decimal digit of Unicode 15, e.g. '٣' (Arabic-Indic three) or '３' (fullwidth three).
+    Unicode,
+}
+
+/// First code point of every run of ten decimal digits (general category Nd) in Unicode 15.
+const UNICODE_DIGIT_ZEROS: [u32; 68] = [
+    0x30, 0x660, 0x6F0, 0x7C0, 0x966, 0x9E6, 0xA66, 0xAE6, 0xB66, 0xBE6, 0xC66, 0xCE6, 0xD66,
+    0xDE6, 0xE50, 0xED0, 0xF20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80, 0x1A90,
+    0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0, 0xFF10,
+    0x104A0, 0x10D30, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0, 0x11650,
+    0x116C0, 0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x11F50, 0x16A60, 0x16AC0,
+    0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E4F0, 0x1E950,
+    0x1FBF0,
+];
+
+impl DigitPolicy {
+    pub fn digit_value(self, c: char) -> Option<u32> {
+        match self {
+            DigitPolicy::Ascii => c.to_digit(10),
+            DigitPolicy::Unicode => {
+                let code = c as u32;
+                // Index of the last run starting at or before `code`.
+                let run = UNICODE_DIGIT_ZEROS.partition_point(|zero| *zero <= code);
+                let zero = UNICODE_DIGIT_ZEROS[run.checked_sub(1)?];
+
+                (code - zero < 10).then_some(code - zero)
             }
         }
     }
+}
+
+fn extract_numbers_from_line(line: &str, policy: DigitPolicy) -> Result<u32, ParseError> {
+    let mut digits = line.chars().filter_map(|c| policy.digit_value(c));
+
+    let first = digits
+        .next()
+        .ok_or_else(|| ParseError::new(Day1::DAY, line, "no digit found"))?;
+    let last = digits.next_back().unwrap_or(first);
+
+    Ok(first * 10 + last)
+}
+
+/// Words that count as digits next to the literal digits `0` to `9`. A word may stand for a
+/// multi-digit number, then its leading digit is used when it is the first token of a line
+/// and its trailing digit when it is the last one.
+///
+/// The first token of a line is the one that starts first and the last token the one that
+/// starts last. Of tokens starting at the same byte the longest counts, so "seventeen" is
+/// both the first and the last token of "seventeen" if "seven" is a word as well.
+#[derive(Debug, Clone)]
+pub struct DigitVocabulary {
+    words: Vec<(String, u32)>,
+    automaton: Automaton,
+}
+
+impl DigitVocabulary {
+    /// Literal digits win over words that are spelled the same way.
+    pub fn new<I, S>(words: I) -> DigitVocabulary
+    where
+        I: IntoIterator<Item = (S, u32)>,
+        S: Into<String>,
+    {
+        let words: Vec<(String, u32)> = words
+            .into_iter()
+            .map(|(word, value)| (word.into(), value))
+            .collect();
+
+        let literal = DIGITS.iter().zip(0..).map(|(digit, value)| (*digit, value));
+        let spelled = words.iter().map(|(word, value)| (word.as_str(), *value));
+        let automaton = Automaton::new(literal.chain(spelled));
+
+        DigitVocabulary { words, automaton }
+    }
+
+    /// "one" to "nine", as used by the puzzle. Compiled on first use.
+    pub fn english() -> &'static DigitVocabulary {
+        static ENGLISH: OnceLock<DigitVocabulary> = OnceLock::new();
+
+        ENGLISH.get_or_init(|| DigitVocabulary::new(ENGLISH_WORDS))
+    }
+
+    /// "eins" to "neun". Umlauts are also accepted in their transcribed form.
+    pub fn german() -> DigitVocabulary {
+        DigitVocabulary::new([
+            ("eins", 1),
+            ("zwei", 2),
+            ("drei", 3),
+            ("vier", 4),
+            ("fünf", 5),
+            ("fuenf", 5),
+            ("sechs", 6),
+            ("sieben", 7),
+            ("acht", 8),
+            ("neun", 9),
+        ])
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words
+            .iter()
+            .map(|(word, value)| (word.as_str(), *value))
+    }
+
+    pub fn find_first(&self, line: &str) -> Option<Token> {
+        self.automaton.find_first(line)
+    }
 
-    fn is_complete(&self) -> bool {
-        self.current_index + 1 >= self.value.len()
+    pub fn find_last(&self, line: &str) -> Option<Token> {
+        self.automaton.find_last(line)
+    }
+
+    pub fn find_first_and_last(&self, line: &str) -> Option<(Token, Token)> {
+        self.automaton.find_first_and_last(line)
+    }
+
+    /// Every digit and word of the line ordered by start, see [`TokenMode`] for overlaps. With
+    /// [`TokenMode::Overlapping`] the first of them is the first token of the line.
+    pub fn tokenize(&self, line: &str, mode: TokenMode) -> Vec<Token> {
+        let tokens = self.automaton.find_all(line);
+
+        match mode {
+            TokenMode::Overlapping => tokens,
+            TokenMode::Greedy => {
+                let mut end = 0;
+                tokens
+                    .into_iter()
+                    .filter(|token| {
+                        let keep = token.start >= end;
+                        if keep {
+                            end = token.end;
+                        }
+                        keep
+                    })
+                    .collect()
+            }
+        }
     }
 }
 
-pub fn puzzle2() -> Result<u32, &'static str> {
-    PUZZLE_1_INPUT
-        .lines()
-        .map(extract_numbers_and_spelled_numbers_from_line)
-        .try_fold(0, |acc, curr| Ok(acc + curr?))
+/// How tokens that share letters, like "two" and "one" in "twone", are reported.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum TokenMode {
+    /// Every token, "twone" is two and one. Of tokens starting at the same byte the longest
+    /// comes first.
+    #[default]
+    Overlapping,
+    /// Left to right without overlaps, the longest token starting first wins. "twone" is two.
+    Greedy,
 }
 
-fn extract_numbers_and_spelled_numbers_from_line(line: &str) -> Result<u32, &'static str> {
-    let first = find_number(line, false).ok_or("Could not find first digit")?;
-    let last = find_number(line, true).ok_or("Could not find last digit")?;
+/// Every digit of the line with its span, using [`DigitVocabulary::english`].
+pub fn tokenize_digits(line: &str, mode: TokenMode) -> Vec<Token> {
+    DigitVocabulary::english().tokenize(line, mode)
+}
 
-    Ok(first * 10 + last)
+fn extract_numbers_and_spelled_numbers_from_line(
+    line: &str,
+    vocabulary: &DigitVocabulary,
+) -> Result<u32, ParseError> {
+    let tokens = vocabulary.tokenize(line, TokenMode::Overlapping);
+
+    CalibrationLine::from_tokens(line, &tokens).map(|calibration| calibration.value)
 }
 
-pub fn find_number(line: &str, reverse: bool) -> Option<u32> {
-    if reverse {
-        find_number_for_iter(line.chars().rev(), reverse)
-    } else {
-        find_number_for_iter(line.chars(), reverse)
-    }
+/// How the calibration value of a line came about.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CalibrationLine<'a> {
+    pub first: CalibrationDigit<'a>,
+    pub last: CalibrationDigit<'a>,
+    pub value: u32,
 }
 
-fn find_number_for_iter<TIter>(char_iter: TIter, reverse: bool) -> Option<u32>
-where
-    TIter: Iterator<Item = char>,
-{
-    let mut matches: Vec<Match> = Vec::with_capacity(10);
+/// The token a digit of a [`CalibrationLine`] was read from.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CalibrationDigit<'a> {
+    pub kind: DigitKind,
+    /// Byte span in the line.
+    pub span: Range<usize>,
+    pub text: &'a str,
+    pub value: u32,
+}
 
-    for c in char_iter {
-        // check for simple numbers
-        if c.is_numeric() {
-            return c.to_digit(10);
-        }
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DigitKind {
+    Literal,
+    Spelled,
+}
 
-        let mut local_matches: Vec<Match> = Vec::with_capacity(matches.capacity());
+impl<'a> CalibrationLine<'a> {
+    /// Part 1, only digits count.
+    pub fn from_digits(line: &'a str, policy: DigitPolicy) -> Result<Self, ParseError> {
+        let mut digits = line.char_indices().filter_map(|(start, c)| {
+            let value = policy.digit_value(c)?;
+            let span = start..start + c.len_utf8();
+
+            Some(CalibrationDigit {
+                kind: DigitKind::Literal,
+                text: &line[span.clone()],
+                span,
+                value,
+            })
+        });
+
+        let first = digits
+            .next()
+            .ok_or_else(|| ParseError::new(Day1::DAY, line, "no digit found"))?;
+        let last = digits.next_back().unwrap_or_else(|| first.clone());
+
+        Ok(CalibrationLine::new(first, last))
+    }
 
-        // Advance all current matches
-        while let Some(m) = matches.pop() {
-            let advanced_match = m.advance(c, reverse);
-            match advanced_match {
-                None => continue,
-                Some(new_match) => {
-                    if new_match.is_complete() {
-                        return Some(new_match.number_value);
-                    }
-                    local_matches.push(new_match)
-                }
+    /// Part 2, from the tokens of [`DigitVocabulary::tokenize`] in
+    /// [`TokenMode::Overlapping`] mode.
+    pub fn from_tokens(line: &'a str, tokens: &[Token]) -> Result<Self, ParseError> {
+        let (first, last) = tokens
+            .first()
+            .zip(tokens.last())
+            .ok_or_else(|| ParseError::new(Day1::DAY, line, "no digit or spelled digit found"))?;
+        // The longest of the tokens starting last comes first.
+        let last = tokens
+            .iter()
+            .find(|token| token.start == last.start)
+            .unwrap_or(last);
+
+        let digit = |token: &Token| {
+            let text = &line[token.start..token.end];
+            let literal = text.len() == 1 && text.as_bytes()[0].is_ascii_digit();
+
+            CalibrationDigit {
+                kind: if literal {
+                    DigitKind::Literal
+                } else {
+                    DigitKind::Spelled
+                },
+                span: token.start..token.end,
+                text,
+                value: token.value,
             }
-        }
+        };
+
+        Ok(CalibrationLine::new(digit(first), digit(last)))
+    }
 
-        //Spawn new matches
-        Match::spawn_into(c, &mut local_matches, reverse);
+    fn new(first: CalibrationDigit<'a>, last: CalibrationDigit<'a>) -> Self {
+        let value = leading_digit(first.value) * 10 + last.value % 10;
 
-        matches = local_matches;
+        CalibrationLine { first, last, value }
     }
 
-    None
+    /// Marks the bytes of both digits with `^`, aligned below the characters of the line.
+    pub fn underline(&self, line: &str) -> String {
+        let marked =
+            |index: usize| self.first.span.contains(&index) || self.last.span.contains(&index);
+        let marks: String = line
+            .char_indices()
+            .map(|(index, _)| if marked(index) { '^' } else { ' ' })
+            .collect();
+
+        marks.trim_end().to_string()
+    }
 }
 
-enum CharsOrReveresed<'a> {
-    Chars(Chars<'a>),
-    Rev(Rev<Chars<'a>>),
+impl Display for CalibrationDigit<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            DigitKind::Literal => write!(f, "digit {:?}", self.text),
+            DigitKind::Spelled => write!(f, "word {:?} ({})", self.text, self.value),
+        }
+    }
 }
 
-/*impl Deref for CharsOrReveresed<'_> {
-    type Target = dyn Iterator<Item = char>;
+impl Display for CalibrationLine<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} from {} and {}", self.value, self.first, self.last)
+    }
+}
 
-    fn deref(&self) -> &Self::Target {
-        match self {
-            CharsOrReveresed::Chars(c) => c,
-            CharsOrReveresed::Rev(r) => &r.clone(),
+/// Every line with the digits both parts use underlined, for `--explain`.
+pub fn explain(input: &str) -> String {
+    let vocabulary = DigitVocabulary::english();
+    let mut output = String::new();
+
+    for (index, line) in input.lines().enumerate() {
+        output.push_str(&format!("{:>5}  {}\n", index + 1, line));
+        let parts = [
+            CalibrationLine::from_digits(line, DigitPolicy::Ascii),
+            CalibrationLine::from_tokens(line, &vocabulary.tokenize(line, TokenMode::Overlapping)),
+        ];
+        for (part, calibration) in (1..).zip(parts) {
+            let row = match calibration {
+                Ok(calibration) => {
+                    let underline = calibration.underline(line);
+                    let padding = line
+                        .chars()
+                        .count()
+                        .saturating_sub(underline.chars().count());
+                    format!(
+                        "{}{}  part {}: {}",
+                        underline,
+                        " ".repeat(padding),
+                        part,
+                        calibration
+                    )
+                }
+                Err(err) => format!(
+                    "{}  part {}: {}",
+                    " ".repeat(line.chars().count()),
+                    part,
+                    err.reason
+                ),
+            };
+            output.push_str(&format!("{:>5}  {}\n", "", row));
         }
     }
-}*/
 
-impl Iterator for CharsOrReveresed<'_> {
-    type Item = char;
+    output
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        match self {
-            CharsOrReveresed::Chars(c) => c.next(),
-            CharsOrReveresed::Rev(r) => r.next(),
-        }
+fn leading_digit(mut value: u32) -> u32 {
+    while value >= 10 {
+        value /= 10;
     }
+    value
 }
 
-fn find_number_boxed(line: &str, reverse: bool) -> Option<u32> {
-    let char_iter: Box<dyn Iterator<Item = char>> = if reverse {
-        Box::new(line.chars())
+/// Value of the first token, or the last one if `reverse` is set.
+pub fn find_number(line: &str, reverse: bool, vocabulary: &DigitVocabulary) -> Option<u32> {
+    let token = if reverse {
+        vocabulary.find_last(line)
     } else {
-        Box::new(line.chars().rev())
+        vocabulary.find_first(line)
     };
 
-    let mut matches: Vec<Match> = Vec::with_capacity(10);
+    token.map(|token| token.value)
+}
 
-    for c in char_iter {
-        // check for simple numbers
-        if c.is_numeric() {
-            return c.to_digit(10);
-        }
+/// First and last token of a line, a digit or a word of the vocabulary, found by reading the
+/// characters from both ends in turns. Every character is read once, unless the first and the
+/// last token share characters like in "twone" or the first token might still grow into a
+/// longer word. Agrees with [`DigitVocabulary::find_first_and_last`].
+pub struct DigitScanner<'v, I> {
+    chars: I,
+    vocabulary: &'v DigitVocabulary,
+}
 
-        let mut local_matches: Vec<Match> = Vec::with_capacity(matches.capacity());
+impl<'v, I> DigitScanner<'v, I>
+where
+    I: DoubleEndedIterator<Item = char>,
+{
+    pub fn new(chars: I, vocabulary: &'v DigitVocabulary) -> DigitScanner<'v, I> {
+        DigitScanner { chars, vocabulary }
+    }
 
-        // Advance all current matches
-        while let Some(m) = matches.pop() {
-            let advanced_match = m.advance(c, reverse);
-            match advanced_match {
-                None => continue,
-                Some(new_match) => {
-                    if new_match.is_complete() {
-                        return Some(new_match.number_value);
+    pub fn first_and_last(mut self) -> Option<(u32, u32)> {
+        let mut front = Matcher::new(self.vocabulary, false);
+        let mut back = Matcher::new(self.vocabulary, true);
+        let (mut first, mut last) = (None, None);
+        // What one end has read, in case the other end has to read past it.
+        let mut front_read = Vec::new();
+        let mut back_read = Vec::new();
+
+        loop {
+            let mut read = false;
+            if first.is_none() {
+                if let Some(c) = self.chars.next() {
+                    if last.is_none() {
+                        front_read.push(c);
+                    }
+                    first = front.push(c);
+                    read = true;
+                }
+            }
+            if last.is_none() {
+                if let Some(c) = self.chars.next_back() {
+                    if first.is_none() {
+                        back_read.push(c);
                     }
-                    local_matches.push(new_match)
+                    last = back.push(c);
+                    read = true;
                 }
             }
+            if !read {
+                break;
+            }
         }
 
-        //Spawn new matches
-        Match::spawn_into(c, &mut local_matches, reverse);
+        // The ends met, continue where the other end started.
+        if first.is_none() {
+            first = back_read.iter().rev().find_map(|c| front.push(*c));
+        }
+        if last.is_none() {
+            last = front_read.iter().rev().find_map(|c| back.push(*c));
+        }
 
-        matches = local_matches;
+        first.or_else(|| front.finish()).zip(last)
     }
-
-    None
 }
 
-fn find_number_enum(line: &str, reverse: bool) -> Option<u32> {
-    let base: CharsOrReveresed = if reverse {
-        CharsOrReveresed::Rev(line.chars().rev())
-    } else {
-        CharsOrReveresed::Chars(line.chars())
-    };
-
-    let mut matches: Vec<Match> = Vec::with_capacity(10);
-
-    // TODO ohne impl Iterator => deref trait
+/// Feeds characters read in one direction to the automaton of a vocabulary.
+struct Matcher<'v> {
+    cursor: Cursor<'v>,
+    reverse: bool,
+    /// Bytes read so far.
+    read: usize,
+    /// Start and value of the first token found so far, only used when reading forward.
+    candidate: Option<(usize, u32)>,
+}
 
-    for c in base {
-        // check for simple numbers
-        if c.is_numeric() {
-            return c.to_digit(10);
+impl<'v> Matcher<'v> {
+    fn new(vocabulary: &'v DigitVocabulary, reverse: bool) -> Matcher<'v> {
+        Matcher {
+            cursor: vocabulary.automaton.cursor(reverse),
+            reverse,
+            read: 0,
+            candidate: None,
         }
+    }
 
-        let mut local_matches: Vec<Match> = Vec::with_capacity(matches.capacity());
+    /// Reads the next character and returns the value of the first or last token once no
+    /// longer one starting at the same byte can follow.
+    fn push(&mut self, c: char) -> Option<u32> {
+        let mut buf = [0; 4];
+        let bytes = c.encode_utf8(&mut buf).as_bytes();
+
+        // Backwards the first token completed is the one starting last, tokens are whole
+        // characters, so none is completed in the middle of one.
+        if self.reverse {
+            return bytes
+                .iter()
+                .rev()
+                .find_map(|byte| self.cursor.push(*byte))
+                .map(|(value, _)| value);
+        }
 
-        // Advance all current matches
-        while let Some(m) = matches.pop() {
-            let advanced_match = m.advance(c, reverse);
-            match advanced_match {
-                None => continue,
-                Some(new_match) => {
-                    if new_match.is_complete() {
-                        return Some(new_match.number_value);
-                    }
-                    local_matches.push(new_match)
+        for byte in bytes {
+            self.read += 1;
+            if let Some((value, len)) = self.cursor.push(*byte) {
+                let start = self.read - len;
+                // Starting at the same byte as the candidate means being longer.
+                if self.candidate.is_none_or(|(first, _)| start <= first) {
+                    self.candidate = Some((start, value));
                 }
             }
         }
 
-        //Spawn new matches
-        Match::spawn_into(c, &mut local_matches, reverse);
+        self.candidate
+            .filter(|(start, _)| *start < self.read - self.cursor.depth())
+            .map(|(_, value)| value)
+    }
 
-        matches = local_matches;
+    /// The token found when there is nothing left to read.
+    fn finish(self) -> Option<u32> {
+        self.candidate.map(|(_, value)| value)
     }
+}
+
+/// Reads until the first English digit, the characters have to be reversed if `reverse` is set.
+fn scan<TIter>(mut char_iter: TIter, reverse: bool) -> Option<u32>
+where
+    TIter: Iterator<Item = char>,
+{
+    let mut matcher = Matcher::new(DigitVocabulary::english(), reverse);
+    let found = char_iter.find_map(|c| matcher.push(c));
 
-    None
+    found.or_else(|| matcher.finish())
 }
 
-fn find_number_ref(line: &str, reverse: bool) -> Option<u32> {
-    let mut chars = line.chars();
-    let mut rev = line.chars().rev();
+// The `find_number_*` functions only differ in how they get hold of a forward or reversed
+// iterator. They are kept to compare the cost of that in the benchmarks.
 
-    let base: &mut dyn Iterator<Item = char> = if reverse { &mut rev } else { &mut chars };
+/// Benchmark fixture, generic over the iterator.
+pub fn find_number_generic(line: &str, reverse: bool) -> Option<u32> {
+    if reverse {
+        scan(line.chars().rev(), reverse)
+    } else {
+        scan(line.chars(), reverse)
+    }
+}
 
-    let mut matches: Vec<Match> = Vec::with_capacity(10);
+enum CharsOrReveresed<'a> {
+    Chars(Chars<'a>),
+    Rev(Rev<Chars<'a>>),
+}
 
-    for c in base {
-        // check for simple numbers
-        if c.is_numeric() {
-            return c.to_digit(10);
+impl Iterator for CharsOrReveresed<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            CharsOrReveresed::Chars(c) => c.next(),
+            CharsOrReveresed::Rev(r) => r.next(),
         }
+    }
+}
 
-        let mut local_matches: Vec<Match> = Vec::with_capacity(matches.capacity());
+/// Benchmark fixture, boxed iterator.
+pub fn find_number_boxed(line: &str, reverse: bool) -> Option<u32> {
+    let char_iter: Box<dyn Iterator<Item = char>> = if reverse {
+        Box::new(line.chars().rev())
+    } else {
+        Box::new(line.chars())
+    };
 
-        // Advance all current matches
-        while let Some(m) = matches.pop() {
-            let advanced_match = m.advance(c, reverse);
-            match advanced_match {
-                None => continue,
-                Some(new_match) => {
-                    if new_match.is_complete() {
-                        return Some(new_match.number_value);
-                    }
-                    local_matches.push(new_match)
-                }
-            }
-        }
+    scan(char_iter, reverse)
+}
 
-        //Spawn new matches
-        Match::spawn_into(c, &mut local_matches, reverse);
+/// Benchmark fixture, iterator wrapped in an enum.
+pub fn find_number_enum(line: &str, reverse: bool) -> Option<u32> {
+    let base: CharsOrReveresed = if reverse {
+        CharsOrReveresed::Rev(line.chars().rev())
+    } else {
+        CharsOrReveresed::Chars(line.chars())
+    };
 
-        matches = local_matches;
-    }
+    scan(base, reverse)
+}
+
+/// Benchmark fixture, iterator behind a trait object reference.
+pub fn find_number_ref(line: &str, reverse: bool) -> Option<u32> {
+    let mut chars = line.chars();
+    let mut rev = line.chars().rev();
+
+    let base: &mut dyn Iterator<Item = char> = if reverse { &mut rev } else { &mut chars };
 
-    None
+    scan(base, reverse)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::days::day1::{
-        extract_numbers_and_spelled_numbers_from_line, extract_numbers_from_line, Match,
+        explain, extract_numbers_and_spelled_numbers_from_line, extract_numbers_from_line,
+        find_number, find_number_boxed, find_number_enum, find_number_generic, find_number_ref,
+        sum_lines, sum_reader, tokenize_digits, CalibrationDigit, CalibrationLine, Day1, DigitKind,
+        DigitPolicy, DigitScanner, DigitVocabulary, InvalidLinePolicy, Token, TokenMode,
     };
+    use crate::days::error::{Error, ParseError};
+    use crate::days::input::puzzle_input;
+    #[cfg(feature = "swar")]
+    use crate::days::swar;
+    use crate::days::Solver;
+    use proptest::prelude::*;
+    use std::io::{BufReader, Read};
     use test_case::test_case;
 
     #[test_case("1abc2", 12)]
@@ -337,11 +791,68 @@ mod tests {
     #[test_case("a1b2c3d4e5f", 15)]
     #[test_case("treb7uchet", 77)]
     fn it_extracts_numbers(line: &str, expected: u32) {
-        let result = extract_numbers_from_line(line).unwrap();
+        let result = extract_numbers_from_line(line, DigitPolicy::Ascii).unwrap();
+
+        assert_eq!(expected, result)
+    }
+
+    #[test_case("a٣b7", DigitPolicy::Unicode, Ok(37) ; "arabic indic digit")]
+    #[test_case("a٣b7", DigitPolicy::Ascii, Ok(77) ; "arabic indic digit ignored")]
+    #[test_case("½五Ⅻ", DigitPolicy::Unicode, Err(()) ; "non decimal numerics")]
+    #[test_case("½x4", DigitPolicy::Ascii, Ok(44) ; "vulgar fraction ignored")]
+    #[test_case("ö１２ä", DigitPolicy::Unicode, Ok(12) ; "fullwidth digits")]
+    #[test_case("ö１２ä", DigitPolicy::Ascii, Err(()) ; "fullwidth digits ignored")]
+    #[test_case("𝟘x𝟡", DigitPolicy::Unicode, Ok(9) ; "mathematical digits")]
+    fn it_extracts_numbers_with_digit_policy(
+        line: &str,
+        policy: DigitPolicy,
+        expected: Result<u32, ()>,
+    ) {
+        let result = extract_numbers_from_line(line, policy).map_err(|_| ());
 
         assert_eq!(expected, result)
     }
 
+    proptest! {
+        #[test]
+        fn it_never_panics_on_arbitrary_lines(line in any::<String>()) {
+            for policy in [DigitPolicy::Ascii, DigitPolicy::Unicode] {
+                if let Ok(value) = extract_numbers_from_line(&line, policy) {
+                    prop_assert!(value <= 99);
+                }
+            }
+        }
+
+        #[test]
+        fn ascii_policy_uses_first_and_last_ascii_digit(line in "[a-z0-9٠-٩½五\\PC]{0,20}") {
+            let digits: Vec<u32> = line
+                .bytes()
+                .filter(u8::is_ascii_digit)
+                .map(|byte| u32::from(byte - b'0'))
+                .collect();
+            let expected = digits.first().zip(digits.last()).map(|(first, last)| first * 10 + last);
+
+            prop_assert_eq!(expected, extract_numbers_from_line(&line, DigitPolicy::Ascii).ok());
+        }
+
+        #[test]
+        fn unicode_digits_are_numeric(c in any::<char>()) {
+            if let Some(value) = DigitPolicy::Unicode.digit_value(c) {
+                prop_assert!(c.is_numeric());
+                prop_assert!(value < 10);
+            }
+            prop_assert_eq!(c.to_digit(10), DigitPolicy::Ascii.digit_value(c));
+        }
+
+        #[test]
+        fn unicode_policy_agrees_on_ascii_lines(line in "[ -~]{0,30}") {
+            prop_assert_eq!(
+                extract_numbers_from_line(&line, DigitPolicy::Ascii).ok(),
+                extract_numbers_from_line(&line, DigitPolicy::Unicode).ok()
+            );
+        }
+    }
+
     #[test_case("1abc2", 12)]
     #[test_case("pqr3stu8vwx", 38)]
     #[test_case("a1b2c3d4e5f", 15)]
@@ -354,64 +865,473 @@ mod tests {
     #[test_case("4nineeightseven2", 42)]
     #[test_case("7pqrstsixteen", 76)]
     fn it_extracts_numbers_and_spelled_numbers(line: &str, expected: u32) {
-        let result = extract_numbers_and_spelled_numbers_from_line(line).unwrap();
+        let result =
+            extract_numbers_and_spelled_numbers_from_line(line, DigitVocabulary::english())
+                .unwrap();
 
         assert_eq!(expected, result)
     }
 
     #[test]
-    fn it_advances_and_completes() {
-        let match_one = Match {
-            current_index: 0,
-            value: "one",
-            number_value: 1,
+    fn it_reports_line_of_bad_input() {
+        let lines = Day1::parse("1abc2\nabcxyz\n").unwrap();
+        let err = Day1::part1(&lines).unwrap_err();
+
+        assert_eq!(
+            r#"day 1, line 2: no digit found in "abcxyz""#,
+            err.to_string()
+        );
+    }
+
+    #[cfg(feature = "swar")]
+    fn scalar_part1(input: &str) -> Option<u32> {
+        Day1::part1_with::<u32>(input, InvalidLinePolicy::FailFast)
+            .ok()
+            .map(|calibration| calibration.sum)
+    }
+
+    #[cfg(feature = "swar")]
+    #[test]
+    fn swar_agrees_with_scalar_on_puzzle_input() {
+        let Some(input) = puzzle_input(&Day1) else {
+            return;
         };
 
-        let match_one = match_one.advance('n', false).unwrap();
-        assert!(!match_one.is_complete());
+        assert_eq!(
+            scalar_part1(&input),
+            swar::sum_first_and_last_digits(input.as_bytes())
+        );
+    }
 
-        let match_one = match_one.advance('e', false).unwrap();
-        assert!(match_one.is_complete());
+    #[cfg(feature = "swar")]
+    proptest! {
+        #[test]
+        fn swar_agrees_with_scalar(input in "([a-z0-9٣\\r]{0,20}\n){0,10}[a-z0-9]{0,5}") {
+            prop_assert_eq!(
+                scalar_part1(&input),
+                swar::sum_first_and_last_digits(input.as_bytes())
+            );
+        }
     }
 
     #[test]
-    fn it_advances_in_reverse_and_completes() {
-        let match_one = Match {
-            current_index: 0,
-            value: "one",
-            number_value: 1,
+    fn streaming_agrees_with_parsed_input() {
+        let Some(input) = puzzle_input(&Day1) else {
+            return;
         };
+        let lines = Day1::parse(&input).unwrap();
+
+        assert_eq!(
+            Day1::part1(&lines).unwrap(),
+            Day1::part1_streaming::<u32, _>(input.as_bytes(), InvalidLinePolicy::FailFast)
+                .unwrap()
+                .sum
+                .into()
+        );
+        assert_eq!(
+            Day1::part2(&lines).unwrap(),
+            Day1::part2_streaming::<u32, _>(input.as_bytes(), InvalidLinePolicy::FailFast)
+                .unwrap()
+                .sum
+                .into()
+        );
+    }
 
-        let match_one = match_one.advance('n', true).unwrap();
-        assert!(!match_one.is_complete());
+    #[test]
+    fn it_streams_inputs_larger_than_the_reader_buffer() {
+        let copies = 10_000;
+        let input = "1abc2\r\npqr3stu8vwx\n".repeat(copies);
+        let reader = BufReader::with_capacity(16, input.as_bytes()).chain("treb7uchet".as_bytes());
 
-        let match_one = match_one.advance('o', true).unwrap();
-        assert!(match_one.is_complete());
+        let calibration =
+            Day1::part1_streaming::<u32, _>(reader, InvalidLinePolicy::FailFast).unwrap();
+
+        assert_eq!(50 * copies as u32 + 77, calibration.sum);
+        assert_eq!(2 * copies + 1, calibration.lines);
     }
-}
 
-#[cfg(test)]
-mod bench {
-    use super::*;
-    use test::Bencher;
+    #[test_case(b"1abc2\nabcxyz\n", r#"day 1, line 2: no digit found in "abcxyz""# ; "missing digit")]
+    #[test_case(b"1abc2\n\nx", r#"day 1, line 2: no digit found in """# ; "empty line")]
+    #[test_case(b"1\n2a\xffb", "day 1, line 2, column 3: invalid UTF-8 in \"2a\u{fffd}b\"" ; "invalid utf8")]
+    fn it_reports_line_of_bad_streamed_input(input: &[u8], expected: &str) {
+        let err = Day1::part1_streaming::<u32, _>(input, InvalidLinePolicy::FailFast).unwrap_err();
+
+        assert_eq!(expected, err.to_string());
+    }
+
+    #[test_case(InvalidLinePolicy::Skip, 67, 2)]
+    #[test_case(InvalidLinePolicy::Zero, 67, 4)]
+    fn it_reports_invalid_lines(policy: InvalidLinePolicy, sum: u32, lines: usize) {
+        let input = "1abc2\nabcxyz\nä\nx5x\n";
+        let streamed = Day1::part1_streaming::<u32, _>(input.as_bytes(), policy).unwrap();
+        let calibration = Day1::part1_with::<u32>(input, policy).unwrap();
+
+        assert_eq!((sum, lines), (calibration.sum, calibration.lines));
+        assert_eq!(
+            vec![(Some(2), "no digit found"), (Some(3), "no digit found")],
+            calibration
+                .diagnostics
+                .iter()
+                .map(|err| (err.line, err.reason.as_str()))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(calibration, streamed);
+    }
+
+    #[test]
+    fn it_reports_invalid_utf8_when_streaming() {
+        let input = b"1\n\xff\n2";
+        let calibration =
+            Day1::part1_streaming::<u32, _>(&input[..], InvalidLinePolicy::Skip).unwrap();
+
+        assert_eq!(33, calibration.sum);
+        assert_eq!(Some(2), calibration.diagnostics[0].line);
+        assert_eq!("invalid UTF-8", calibration.diagnostics[0].reason);
+    }
+
+    #[test_case("two1nine\nabc\nxtwone3four", 29 + 24)]
+    #[test_case("", 0)]
+    fn it_skips_lines_without_spelled_digits(input: &str, expected: u32) {
+        let calibration = Day1::part2_with::<u32>(input, InvalidLinePolicy::Skip).unwrap();
+
+        assert_eq!(expected, calibration.sum);
+    }
+
+    #[test]
+    fn it_reports_sums_that_do_not_fit() {
+        // Every line is worth more than half of `u32::MAX`.
+        let input = "1\n2\n3\n";
+        let extract = |_: &str| Ok(u32::MAX / 2 + 1);
+
+        for err in [
+            sum_lines::<u32, _>(input, InvalidLinePolicy::FailFast, extract).unwrap_err(),
+            sum_reader::<u32, _, _>(input.as_bytes(), InvalidLinePolicy::FailFast, extract)
+                .unwrap_err(),
+        ] {
+            assert_eq!(
+                "day 1, line 2: answer does not fit into u32",
+                err.to_string()
+            );
+        }
+
+        let calibration = sum_lines::<u64, _>(input, InvalidLinePolicy::FailFast, extract).unwrap();
+        assert_eq!(3 * (u64::from(u32::MAX) / 2 + 1), calibration.sum);
+        assert_eq!(
+            calibration,
+            sum_reader(input.as_bytes(), InvalidLinePolicy::FailFast, extract).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "43 million lines, run with --release")]
+    fn it_reports_puzzle_inputs_that_do_not_fit() {
+        // 99 per line, one line more than `u32` can hold.
+        let lines = u32::MAX / 99 + 1;
+        let input = "99\n".repeat(lines as usize);
+
+        let err = Day1::part1_with::<u32>(&input, InvalidLinePolicy::FailFast).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Overflow { day: 1, line: Some(line), total: "u32" } if line == lines as usize
+        ));
+
+        let calibration = Day1::part1_with::<u64>(&input, InvalidLinePolicy::FailFast).unwrap();
+        assert_eq!(99 * u64::from(lines), calibration.sum);
+        assert!(calibration.sum > u64::from(u32::MAX));
+    }
+
+    #[test]
+    fn it_fails_fast_by_default() {
+        let err = Day1::part2_with::<u32>("1\nabc\n2", InvalidLinePolicy::default()).unwrap_err();
+
+        assert!(matches!(
+            err,
+            Error::Parse(ParseError { line: Some(2), .. })
+        ));
+    }
+
+    #[test]
+    fn vocabulary_agrees_with_find_number_generic() {
+        let Some(input) = puzzle_input(&Day1) else {
+            return;
+        };
+        let vocabulary = DigitVocabulary::english();
+
+        for line in input.lines() {
+            assert_eq!(
+                find_number_generic(line, false),
+                find_number(line, false, vocabulary),
+                "first digit of {}",
+                line
+            );
+            assert_eq!(
+                find_number_generic(line, true),
+                find_number(line, true, vocabulary),
+                "last digit of {}",
+                line
+            );
+        }
+    }
+
+    fn token(value: u32, start: usize, end: usize) -> Token {
+        Token { value, start, end }
+    }
+
+    #[test_case("twone", TokenMode::Overlapping, vec![token(2, 0, 3), token(1, 2, 5)])]
+    #[test_case("twone", TokenMode::Greedy, vec![token(2, 0, 3)])]
+    #[test_case("eightwothree", TokenMode::Overlapping, vec![token(8, 0, 5), token(2, 4, 7), token(3, 7, 12)])]
+    #[test_case("eightwothree", TokenMode::Greedy, vec![token(8, 0, 5), token(3, 7, 12)])]
+    #[test_case("x1oneight", TokenMode::Greedy, vec![token(1, 1, 2), token(1, 2, 5)])]
+    #[test_case("abc", TokenMode::Greedy, vec![])]
+    fn it_tokenizes_digits(line: &str, mode: TokenMode, expected: Vec<Token>) {
+        assert_eq!(expected, tokenize_digits(line, mode));
+    }
+
+    #[test_case("seventeen", TokenMode::Overlapping, vec![token(17, 0, 9), token(7, 0, 5)])]
+    #[test_case("seventeen", TokenMode::Greedy, vec![token(17, 0, 9)])]
+    #[test_case("sevenine", TokenMode::Greedy, vec![token(7, 0, 5)])]
+    fn it_tokenizes_words_that_contain_others(line: &str, mode: TokenMode, expected: Vec<Token>) {
+        let vocabulary = DigitVocabulary::new([("seven", 7), ("seventeen", 17), ("nine", 9)]);
+
+        assert_eq!(expected, vocabulary.tokenize(line, mode));
+    }
+
+    proptest! {
+        #[test]
+        fn tokens_agree_with_find_number(line in "[a-z0-9]{0,30}") {
+            let vocabulary = DigitVocabulary::english();
+            let tokens = tokenize_digits(&line, TokenMode::Overlapping);
+
+            prop_assert_eq!(find_number(&line, false, vocabulary), tokens.first().map(|t| t.value));
+            prop_assert_eq!(find_number(&line, true, vocabulary), tokens.last().map(|t| t.value));
+        }
+
+        #[test]
+        fn greedy_tokens_do_not_overlap(line in "[a-z0-9]{0,30}") {
+            let tokens = tokenize_digits(&line, TokenMode::Greedy);
+
+            prop_assert!(tokens.windows(2).all(|pair| pair[0].end <= pair[1].start));
+        }
+    }
 
-    #[bench]
-    fn bench_find_number_generic(b: &mut Bencher) {
-        b.iter(|| find_number("7pqrstsixteen", false))
+    #[test]
+    fn it_records_the_tokens_of_a_calibration_line() {
+        let calibration = CalibrationLine::from_tokens(
+            "xtwone3",
+            &tokenize_digits("xtwone3", TokenMode::Overlapping),
+        );
+
+        assert_eq!(
+            Ok(CalibrationLine {
+                first: CalibrationDigit {
+                    kind: DigitKind::Spelled,
+                    span: 1..4,
+                    text: "two",
+                    value: 2,
+                },
+                last: CalibrationDigit {
+                    kind: DigitKind::Literal,
+                    span: 6..7,
+                    text: "3",
+                    value: 3,
+                },
+                value: 23,
+            }),
+            calibration
+        );
+    }
+
+    #[test_case("a٣b7", "37 from digit \"٣\" and digit \"7\"", " ^ ^")]
+    #[test_case("ä7", "77 from digit \"7\" and digit \"7\"", " ^")]
+    fn it_describes_calibration_lines(line: &str, description: &str, underline: &str) {
+        let calibration = CalibrationLine::from_digits(line, DigitPolicy::Unicode).unwrap();
+
+        assert_eq!(description, calibration.to_string());
+        assert_eq!(underline, calibration.underline(line));
+    }
+
+    #[test]
+    fn it_explains_both_parts() {
+        let explanation = explain("eightwo7\n");
+
+        assert_eq!(
+            [
+                "    1  eightwo7",
+                "              ^  part 1: 77 from digit \"7\" and digit \"7\"",
+                "       ^^^^^  ^  part 2: 87 from word \"eight\" (8) and digit \"7\"",
+            ]
+            .join("\n")
+                + "\n",
+            explanation
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn calibration_lines_agree_with_extraction(line in "[a-z0-9٣]{0,20}") {
+            for policy in [DigitPolicy::Ascii, DigitPolicy::Unicode] {
+                prop_assert_eq!(
+                    extract_numbers_from_line(&line, policy).ok(),
+                    CalibrationLine::from_digits(&line, policy).ok().map(|c| c.value)
+                );
+            }
+        }
+    }
+
+    #[test_case("two1nine", Some((2, 9)))]
+    #[test_case("eightwothree", Some((8, 3)))]
+    #[test_case("twone", Some((2, 1)))]
+    #[test_case("oneight", Some((1, 8)))]
+    #[test_case("xxonexx", Some((1, 1)))]
+    #[test_case("abc1", Some((1, 1)))]
+    #[test_case("7", Some((7, 7)))]
+    #[test_case("abc", None)]
+    #[test_case("", None)]
+    fn it_scans_from_both_ends(line: &str, expected: Option<(u32, u32)>) {
+        let vocabulary = DigitVocabulary::english();
+
+        assert_eq!(
+            expected,
+            DigitScanner::new(line.chars(), vocabulary).first_and_last()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn fixtures_agree_with_scanner(
+            line in "(one|two|three|four|five|six|seven|eight|nine|[a-z]|[0-9]){0,12}"
+        ) {
+            let vocabulary = DigitVocabulary::english();
+            let expected = DigitScanner::new(line.chars(), vocabulary).first_and_last();
+            let fixtures: [fn(&str, bool) -> Option<u32>; 4] =
+                [find_number_generic, find_number_boxed, find_number_enum, find_number_ref];
+
+            for fixture in fixtures {
+                prop_assert_eq!(expected, fixture(&line, false).zip(fixture(&line, true)));
+            }
+            prop_assert_eq!(
+                expected,
+                find_number(&line, false, vocabulary).zip(find_number(&line, true, vocabulary))
+            );
+        }
+    }
+
+    #[test_case("zweiundvierzig", 24)]
+    #[test_case("xfünf7achtx", 58)]
+    #[test_case("fuenfxeinsneun", 59)]
+    #[test_case("einsechs", 16)]
+    fn it_extracts_german_spelled_numbers(line: &str, expected: u32) {
+        let vocabulary = DigitVocabulary::german();
+        let result = extract_numbers_and_spelled_numbers_from_line(line, &vocabulary).unwrap();
+
+        assert_eq!(expected, result)
+    }
+
+    #[test_case("zero1two", 2)]
+    #[test_case("twelvexxthirteen", 13)]
+    #[test_case("9xxtwelve", 92)]
+    #[test_case("zeroxx", 0)]
+    fn it_extracts_numbers_with_custom_vocabulary(line: &str, expected: u32) {
+        let vocabulary =
+            DigitVocabulary::new([("zero", 0), ("two", 2), ("twelve", 12), ("thirteen", 13)]);
+        let result = extract_numbers_and_spelled_numbers_from_line(line, &vocabulary).unwrap();
+
+        assert_eq!(expected, result)
     }
 
-    #[bench]
-    fn bench_find_number_boxed(b: &mut Bencher) {
-        b.iter(|| find_number_boxed("7pqrstsixteen", false))
+    #[test]
+    fn first_and_last_tokens_agree_on_words_that_contain_others() {
+        let vocabulary = DigitVocabulary::new([("seven", 7), ("seventeen", 17)]);
+        let tokens = vocabulary.tokenize("seventeen", TokenMode::Overlapping);
+        let calibration = CalibrationLine::from_tokens("seventeen", &tokens).unwrap();
+        let (first, last) = vocabulary.find_first_and_last("seventeen").unwrap();
+
+        assert_eq!(Some(17), tokens.first().map(|token| token.value));
+        assert_eq!(Some(17), find_number("seventeen", false, &vocabulary));
+        assert_eq!(Some(17), find_number("seventeen", true, &vocabulary));
+        assert_eq!((17, 17), (first.value, last.value));
+        assert_eq!((17, 17), (calibration.first.value, calibration.last.value));
+        assert_eq!(17, calibration.value);
+        assert_eq!(
+            Some((17, 17)),
+            DigitScanner::new("seventeen".chars(), &vocabulary).first_and_last()
+        );
     }
 
-    #[bench]
-    fn bench_find_number_enum(b: &mut Bencher) {
-        b.iter(|| find_number_enum("7pqrstsixteen", false))
+    /// Words that start with, end with or contain other words.
+    fn nested_vocabulary() -> DigitVocabulary {
+        DigitVocabulary::new([
+            ("seven", 7),
+            ("seventeen", 17),
+            ("teen", 10),
+            ("one", 1),
+            ("eighteen", 18),
+            ("eight", 8),
+        ])
     }
 
-    #[bench]
-    fn bench_find_number_ref(b: &mut Bencher) {
-        b.iter(|| find_number_ref("7pqrstsixteen", false))
+    proptest! {
+        #[test]
+        fn calibration_lines_agree_with_find_number(
+            line in "(seven|seventeen|teen|one|eight|[a-z1-9]){0,8}"
+        ) {
+            let vocabulary = nested_vocabulary();
+            let tokens = vocabulary.tokenize(&line, TokenMode::Overlapping);
+            let calibration = CalibrationLine::from_tokens(&line, &tokens).ok();
+
+            prop_assert_eq!(
+                find_number(&line, false, &vocabulary).zip(find_number(&line, true, &vocabulary)),
+                calibration.map(|c| (c.first.value, c.last.value))
+            );
+        }
+
+        #[test]
+        fn nested_tokens_agree_with_find_number(
+            line in "(seven|seventeen|teen|one|eight|[a-z1-9]){0,8}"
+        ) {
+            let vocabulary = nested_vocabulary();
+            let tokens = vocabulary.tokenize(&line, TokenMode::Overlapping);
+            let last = tokens
+                .iter()
+                .find(|token| Some(token.start) == tokens.last().map(|last| last.start));
+
+            prop_assert_eq!(find_number(&line, false, &vocabulary), tokens.first().map(|t| t.value));
+            prop_assert_eq!(find_number(&line, true, &vocabulary), last.map(|t| t.value));
+        }
+    }
+
+    #[test]
+    fn it_does_not_know_english_words_in_german() {
+        let vocabulary = DigitVocabulary::german();
+
+        assert!(extract_numbers_and_spelled_numbers_from_line("onetwo", &vocabulary).is_err());
+    }
+
+    #[test_case("xfünf7achtx", Some((5, 8)))]
+    #[test_case("fünfünf", Some((5, 5)))]
+    #[test_case("siebenzehnx", Some((17, 17)))]
+    #[test_case("five", None)]
+    fn it_scans_with_other_vocabularies(line: &str, expected: Option<(u32, u32)>) {
+        let german = DigitVocabulary::german();
+        let vocabulary = DigitVocabulary::new(german.words().chain([("siebenzehn", 17)]));
+
+        assert_eq!(
+            expected,
+            DigitScanner::new(line.chars(), &vocabulary).first_and_last()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn scanner_agrees_with_find_first_and_last(
+            line in "(seven|seventeen|teen|eight|fünf|ü|[a-z1-9]){0,8}"
+        ) {
+            let vocabulary = DigitVocabulary::new(nested_vocabulary().words().chain([("fünf", 5)]));
+            let expected = vocabulary
+                .find_first_and_last(&line)
+                .map(|(first, last)| (first.value, last.value));
+
+            prop_assert_eq!(expected, DigitScanner::new(line.chars(), &vocabulary).first_and_last());
+        }
     }
 }
@@ -1,49 +1,166 @@
-use crate::days::input::{PUZZLE_3_INPUT, PUZZLE_3_TEST_INPUT};
+use crate::days::error::{offset_in, Error, ParseError};
+use crate::days::total::Total;
+use crate::days::{Answer, Example, Solver};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::iter::Enumerate;
+use std::str::{FromStr, Lines};
+
+const LOADED_SET: &str = "12 red, 13 green, 14 blue";
+
+/// The colours of the original puzzle, unknown colours are rejected.
+pub const CUBES: Palette<'static> = Palette {
+    colours: &["red", "green", "blue"],
+    strict: true,
+};
+
+/// The colours of the original puzzle and any other colour, used by [`FromStr`].
+pub const ANY_CUBES: Palette<'static> = Palette {
+    strict: false,
+    ..CUBES
+};
+
+pub struct Day2;
+
+impl Solver for Day2 {
+    const DAY: u8 = 2;
+    #[cfg(feature = "embedded-inputs")]
+    const EMBEDDED_INPUT: Option<&'static str> = Some(include_str!("../../inputs/day02.txt"));
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: include_str!("../../inputs/day02.example.txt"),
+            expected: "8",
+        },
+        Example {
+            part: 2,
+            input: include_str!("../../inputs/day02.example.txt"),
+            expected: "2286",
+        },
+    ];
+
+    /// The games are kept so that parsing and both parts can be timed on their own, see
+    /// [`totals`] for answering both parts in a single pass instead.
+    type Parsed = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Ok(Game::parse_all(input, &CUBES).collect::<Result<_, _>>()?)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Answer, Error> {
+        let loaded_set = Set::new(LOADED_SET, &CUBES)?;
+        let sum: u64 = sum_games(parsed, |game| {
+            Some(if game.possible_with(&loaded_set) {
+                game.id.into()
+            } else {
+                0
+            })
+        })?;
+
+        Ok(sum.into())
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Answer, Error> {
+        let sum: u64 = sum_games(parsed, |game| game.minimum_set().power(&CUBES))?;
+
+        Ok(sum.into())
+    }
+}
 
-struct Game {
+/// Sums a value of every game, `None` stands for a value that does not fit into `T`. Games are
+/// numbered like the lines they were parsed from.
+fn sum_games<T, F>(games: &[Game], value: F) -> Result<T, Error>
+where
+    T: Total,
+    F: Fn(&Game) -> Option<T>,
+{
+    games
+        .iter()
+        .enumerate()
+        .try_fold(T::default(), |sum, (index, game)| {
+            value(game)
+                .and_then(|value| sum.checked_add(value))
+                .ok_or_else(|| Error::Overflow {
+                    day: Day2::DAY,
+                    line: Some(index + 1),
+                    total: T::name(),
+                })
+        })
+}
+
+/// The colours a set is made of. With `strict` parsing any other colour is an error,
+/// otherwise unknown colours are accepted as well.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Palette<'a> {
+    pub colours: &'a [&'a str],
+    pub strict: bool,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Game {
     id: u32,
     sets: Vec<Set>,
 }
 
-#[derive(Default, Debug, Eq, PartialEq)]
-struct Set {
-    red: u32,
-    green: u32,
-    blue: u32,
+/// Number of cubes per colour. Colours with zero cubes are never stored, so two sets are
+/// equal if they agree on every colour.
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct Set {
+    cubes: BTreeMap<String, u32>,
 }
 
-impl Game {
-    fn new_multi(input: &str) -> Result<Vec<Game>, String> {
-        input
-            .lines()
-            .map(Game::new)
-            .try_fold(Vec::<Game>::new(), |mut acc, curr| {
-                acc.push(curr?);
-                Ok(acc)
-            })
+/// Lazily parses one game per line, see [`Game::parse_all`]. Every game owns its sets and
+/// colour names, [`totals`] gets by without these allocations.
+pub struct Games<'a> {
+    lines: Enumerate<Lines<'a>>,
+    palette: &'a Palette<'a>,
+}
+
+impl Iterator for Games<'_> {
+    type Item = Result<Game, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, line) = self.lines.next()?;
+
+        Some(Game::new(line, self.palette).map_err(|err| err.at_line(index + 1)))
     }
+}
 
-    fn new(input: &str) -> Result<Game, String> {
-        let (game_str, sets) = input
-            .split_once(':')
-            .ok_or(format!("Could not parse input line into Game: {}", input))?;
+/// Answers of both parts, accumulated in a single pass over the input.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct Totals<T> {
+    pub possible_game_ids: T,
+    pub minimum_set_powers: T,
+}
 
-        let (_, game_str_id) = game_str
-            .split_once(' ')
-            .ok_or(format!("Could not split game id: {}.", game_str,))?;
+impl Game {
+    pub fn parse_all<'a>(input: &'a str, palette: &'a Palette<'a>) -> Games<'a> {
+        Games {
+            lines: input.lines().enumerate(),
+            palette,
+        }
+    }
 
-        let game_id = game_str_id.parse::<u32>().map_err(|err| {
-            format!(
-                "Could not parse game id: {}. Got error: {}",
-                game_str_id, err,
-            )
-        })?;
+    fn new(input: &str, palette: &Palette) -> Result<Game, ParseError> {
+        let (game_id, sets) = split_game(input)?;
 
-        let sets = Set::new_multi(sets)?;
+        let sets = Set::new_multi(sets, palette)
+            .map_err(|err| err.offset_column(offset_in(input, sets)))?;
 
         Ok(Game { id: game_id, sets })
     }
 
+    /// The smallest set of cubes with which every set of this game is possible.
+    pub fn minimum_set(&self) -> Set {
+        self.sets.iter().fold(Set::default(), |mut acc, curr| {
+            for (colour, count) in curr.cubes.iter() {
+                let max = acc.cubes.entry(colour.clone()).or_default();
+                *max = (*max).max(*count);
+            }
+            acc
+        })
+    }
+
     fn possible_with(&self, input: &Set) -> bool {
         for set in self.sets.iter() {
             if !set.possible_with(input) {
@@ -55,75 +172,251 @@ impl Game {
 }
 
 impl Set {
-    fn new_multi(input: &str) -> Result<Vec<Set>, String> {
+    fn new_multi(input: &str, palette: &Palette) -> Result<Vec<Set>, ParseError> {
         input
             .split(';')
-            .map(Set::new)
+            .map(|set| {
+                Set::new(set, palette).map_err(|err| err.offset_column(offset_in(input, set)))
+            })
             .try_fold(Vec::<Set>::new(), |mut acc, curr| {
                 acc.push(curr?);
                 Ok(acc)
             })
     }
 
-    fn new(input: &str) -> Result<Set, String> {
-        let color_pairs = input.split(',');
+    fn new(input: &str, palette: &Palette) -> Result<Set, ParseError> {
         let mut result = Set::default();
 
-        for pair in color_pairs {
-            let (number_str, color) = pair
-                .trim()
-                .split_once(' ')
-                .ok_or(format!("Could not parse set color pair: {}", pair))?;
-
-            let number = number_str.parse::<u32>().map_err(|err| {
-                format!(
-                    "Could not parse set number into u32: {}. Got error {}",
-                    number_str, err
-                )
-            })?;
-
-            match color {
-                "red" => {
-                    result.red = number;
-                }
-                "green" => {
-                    result.green = number;
-                }
-                "blue" => {
-                    result.blue = number;
-                }
-                _ => return Err(format!("Could not determin color, got: {}", color)),
-            }
+        for pair in cube_pairs(input, palette) {
+            let (number, color) = pair?;
+            result.set(color, number);
         }
 
         Ok(result)
     }
 
+    pub fn count(&self, colour: &str) -> u32 {
+        self.cubes.get(colour).copied().unwrap_or(0)
+    }
+
+    pub fn set(&mut self, colour: &str, count: u32) {
+        if count == 0 {
+            self.cubes.remove(colour);
+        } else {
+            self.cubes.insert(colour.to_string(), count);
+        }
+    }
+
+    /// Product of the cube counts of every palette colour and every other colour in the set.
+    /// `None` if the power does not fit into `T`.
+    pub fn power<T: Total>(&self, palette: &Palette) -> Option<T> {
+        power(
+            palette,
+            self.cubes
+                .iter()
+                .map(|(colour, count)| (colour.as_str(), *count)),
+        )
+    }
+
     fn possible_with(&self, input: &Set) -> bool {
-        self.red <= input.red && self.green <= input.green && self.blue <= input.blue
+        self.cubes
+            .iter()
+            .all(|(colour, count)| *count <= input.count(colour))
+    }
+}
+
+/// `Game <id>: <set>; <set>`, see [`Set`] for the format of the sets.
+impl Display for Game {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Game {}:", self.id)?;
+        for (index, set) in self.sets.iter().enumerate() {
+            let separator = if index == 0 { " " } else { "; " };
+            write!(f, "{}{}", separator, set)?;
+        }
+        Ok(())
     }
 }
 
-fn puzzle_3_with_input(games_input: &str, loaded_set: &str) -> Result<u32, String> {
-    let set = Set::new(loaded_set)?;
+impl FromStr for Game {
+    type Err = ParseError;
 
-    Ok(Game::new_multi(games_input)?.iter().fold(0, |acc, curr| {
-        if curr.possible_with(&set) {
-            acc + curr.id
-        } else {
-            acc
+    /// Parses with [`ANY_CUBES`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Game::new(s, &ANY_CUBES)
+    }
+}
+
+/// `<count> <colour>, <count> <colour>` with the colours in alphabetical order. A set without
+/// any cube is printed as nothing.
+impl Display for Set {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, (colour, count)) in self.cubes.iter().enumerate() {
+            let separator = if index == 0 { "" } else { ", " };
+            write!(f, "{}{} {}", separator, count, colour)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Set {
+    type Err = ParseError;
+
+    /// Parses with [`ANY_CUBES`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Set::new(s, &ANY_CUBES)
+    }
+}
+
+impl<const N: usize> From<[(&str, u32); N]> for Set {
+    fn from(value: [(&str, u32); N]) -> Self {
+        let mut set = Set::default();
+        for (colour, count) in value {
+            set.set(colour, count);
+        }
+        set
+    }
+}
+
+/// Splits `Game <id>: <sets>` into the id and the still unparsed sets.
+fn split_game(input: &str) -> Result<(u32, &str), ParseError> {
+    let (game_str, sets) = input
+        .split_once(':')
+        .ok_or_else(|| ParseError::new(Day2::DAY, input, "missing ':' after game id"))?;
+
+    let (_, game_str_id) = game_str
+        .split_once(' ')
+        .ok_or_else(|| ParseError::new(Day2::DAY, game_str, "missing game id").at_column(1))?;
+
+    let game_str_id = game_str_id.trim_start();
+    let game_id = game_str_id.parse::<u32>().map_err(|err| {
+        ParseError::new(Day2::DAY, game_str_id, format!("invalid game id ({})", err))
+            .at_column(offset_in(input, game_str_id) + 1)
+    })?;
+
+    Ok((game_id, sets))
+}
+
+/// The `<count> <colour>` pairs of a single set. Colours borrow from the input. If a colour
+/// appears twice its last count wins, and a set without any pair is blank like the second of
+/// `1 red; ; 2 blue`.
+fn cube_pairs<'a>(
+    input: &'a str,
+    palette: &'a Palette<'a>,
+) -> impl Iterator<Item = Result<(u32, &'a str), ParseError>> + 'a {
+    let pairs = (!input.trim().is_empty()).then(|| input.split(','));
+
+    pairs.into_iter().flatten().map(move |pair| {
+        let pair = pair.trim();
+        let error = |text: &str, reason: String| {
+            ParseError::new(Day2::DAY, text, reason).at_column(offset_in(input, text) + 1)
+        };
+
+        let (number_str, color) = pair
+            .split_once(' ')
+            .map(|(number_str, color)| (number_str, color.trim_start()))
+            .ok_or_else(|| error(pair, "expected '<count> <colour>'".to_string()))?;
+
+        let number = number_str
+            .parse::<u32>()
+            .map_err(|err| error(number_str, format!("invalid count ({})", err)))?;
+
+        if palette.strict && !palette.colours.contains(&color) {
+            return Err(error(color, "unknown colour".to_string()));
         }
-    }))
+
+        Ok((number, color))
+    })
 }
 
-pub fn puzzle3() -> Result<u32, String> {
-    puzzle_3_with_input(PUZZLE_3_INPUT, "12 red, 13 green, 14 blue")
+/// Product of the cube counts of every palette colour and every other colour in `cubes`.
+fn power<'c, T, I>(palette: &Palette, cubes: I) -> Option<T>
+where
+    T: Total,
+    I: Iterator<Item = (&'c str, u32)> + Clone,
+{
+    let count = |colour: &str| {
+        cubes
+            .clone()
+            .find(|(curr, _)| *curr == colour)
+            .map_or(0, |(_, count)| count)
+    };
+    let extra = cubes
+        .clone()
+        .filter(|(colour, _)| !palette.colours.contains(colour))
+        .map(|(_, count)| count);
+
+    palette
+        .colours
+        .iter()
+        .map(|colour| count(colour))
+        .chain(extra)
+        .try_fold(T::from(1), |product, count| {
+            product.checked_mul(count.into())
+        })
+}
+
+/// Sums the ids of the games possible with `loaded_set` and the powers of the minimum sets
+/// in one pass, without allocating a `Game` or `Set` per line.
+pub fn totals<T: Total>(
+    input: &str,
+    palette: &Palette,
+    loaded_set: &Set,
+) -> Result<Totals<T>, Error> {
+    let mut totals = Totals::<T>::default();
+    let mut minimum: Vec<(&str, u32)> = Vec::with_capacity(palette.colours.len());
+    let mut cubes: Vec<(&str, u32)> = Vec::with_capacity(palette.colours.len());
+
+    for (index, line) in input.lines().enumerate() {
+        let (game_id, sets) = split_game(line).map_err(|err| err.at_line(index + 1))?;
+        let mut possible = true;
+        minimum.clear();
+
+        for set in sets.split(';') {
+            // Like `Set::new`, the last count of a colour wins.
+            cubes.clear();
+            for pair in cube_pairs(set, palette) {
+                let (count, colour) =
+                    pair.map_err(|err| err.offset_column(offset_in(line, set)).at_line(index + 1))?;
+
+                match cubes.iter_mut().find(|(curr, _)| *curr == colour) {
+                    Some((_, last)) => *last = count,
+                    None => cubes.push((colour, count)),
+                }
+            }
+
+            for &(colour, count) in cubes.iter() {
+                possible &= count <= loaded_set.count(colour);
+                match minimum.iter_mut().find(|(curr, _)| *curr == colour) {
+                    Some((_, max)) => *max = (*max).max(count),
+                    None => minimum.push((colour, count)),
+                }
+            }
+        }
+
+        let overflow = || Error::Overflow {
+            day: Day2::DAY,
+            line: Some(index + 1),
+            total: T::name(),
+        };
+        if possible {
+            totals.possible_game_ids = totals
+                .possible_game_ids
+                .checked_add(game_id.into())
+                .ok_or_else(overflow)?;
+        }
+        totals.minimum_set_powers = power(palette, minimum.iter().copied())
+            .and_then(|power: T| totals.minimum_set_powers.checked_add(power))
+            .ok_or_else(overflow)?;
+    }
+
+    Ok(totals)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::days::input::PUZZLE_3_TEST_INPUT;
+    use crate::days::input::puzzle_input;
+    use proptest::prelude::*;
     use test_case::test_case;
 
     #[test_case("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green", 1)]
@@ -133,63 +426,46 @@ mod test {
         200
     )]
     fn it_parses_game_id(input: &str, expected_id: u32) {
-        let result = Game::new(input).unwrap();
+        let result = Game::new(input, &CUBES).unwrap();
 
         assert_eq!(expected_id, result.id)
     }
 
-    #[test_case("3 blue, 4 red", Set{red: 4, green: 0, blue: 3})]
-    #[test_case("1 red, 2 green, 6 blue", Set{red: 1, green: 2, blue: 6})]
-    #[test_case("1 red, 20 green, 6 blue", Set{red: 1, green: 20, blue: 6})]
+    #[test_case("3 blue, 4 red", Set::from([("red", 4), ("green", 0), ("blue", 3)]))]
+    #[test_case("1 red, 2 green, 6 blue", Set::from([("red", 1), ("green", 2), ("blue", 6)]))]
+    #[test_case("1 red, 20 green, 6 blue", Set::from([("red", 1), ("green", 20), ("blue", 6)]))]
     fn it_parses_single_set(input: &str, expected: Set) {
-        let result = Set::new(input).unwrap();
+        let result = Set::new(input, &CUBES).unwrap();
 
         assert_eq!(expected, result);
     }
 
     #[test]
+    #[allow(clippy::useless_vec)]
     fn it_parses_multi_set() {
-        let result = Set::new_multi("3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
+        let result =
+            Set::new_multi("3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green", &CUBES).unwrap();
         let expected = vec![
-            Set {
-                red: 4,
-                green: 0,
-                blue: 3,
-            },
-            Set {
-                red: 1,
-                green: 2,
-                blue: 6,
-            },
-            Set {
-                red: 0,
-                green: 2,
-                blue: 0,
-            },
+            Set::from([("red", 4), ("green", 0), ("blue", 3)]),
+            Set::from([("red", 1), ("green", 2), ("blue", 6)]),
+            Set::from([("red", 0), ("green", 2), ("blue", 0)]),
         ];
 
         assert!(expected.iter().all(|x| result.contains(x)))
     }
 
     #[test]
+    #[allow(clippy::useless_vec)]
     fn it_parses_game() {
-        let result = Game::new("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
+        let result = Game::new(
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+            &CUBES,
+        )
+        .unwrap();
         let expected_sets = vec![
-            Set {
-                red: 4,
-                green: 0,
-                blue: 3,
-            },
-            Set {
-                red: 1,
-                green: 2,
-                blue: 6,
-            },
-            Set {
-                red: 0,
-                green: 2,
-                blue: 0,
-            },
+            Set::from([("red", 4), ("green", 0), ("blue", 3)]),
+            Set::from([("red", 1), ("green", 2), ("blue", 6)]),
+            Set::from([("red", 0), ("green", 2), ("blue", 0)]),
         ];
 
         assert!(expected_sets.iter().all(|x| result.sets.contains(x)));
@@ -202,8 +478,8 @@ mod test {
     #[test_case("3 blue, 4 red", "1 green, 3 blue", false)]
     #[test_case("3 blue, 4 red", "3 blue, 4 red", true)]
     fn set_is_possible_with(first_input: &str, second_input: &str, shoud_be_possible: bool) {
-        let first_set = Set::new(first_input).unwrap();
-        let second_set = Set::new(second_input).unwrap();
+        let first_set = Set::new(first_input, &CUBES).unwrap();
+        let second_set = Set::new(second_input, &CUBES).unwrap();
 
         assert_eq!(shoud_be_possible, first_set.possible_with(&second_set))
     }
@@ -219,16 +495,263 @@ mod test {
         false
     )]
     fn game_is_possible_with(game_input: &str, set_input: &str, should_be_possible: bool) {
-        let game = Game::new(game_input).unwrap();
-        let set = Set::new(set_input).unwrap();
+        let game = Game::new(game_input, &CUBES).unwrap();
+        let set = Set::new(set_input, &CUBES).unwrap();
 
         assert_eq!(should_be_possible, game.possible_with(&set));
     }
 
+    #[test_case("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green", Set::from([("red", 4), ("green", 2), ("blue", 6)]))]
+    #[test_case("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red", Set::from([("red", 20), ("green", 13), ("blue", 6)]))]
+    #[test_case("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green", Set::from([("red", 6), ("green", 3), ("blue", 2)]))]
+    fn it_finds_minimum_set(game_input: &str, expected: Set) {
+        let game = Game::new(game_input, &CUBES).unwrap();
+
+        assert_eq!(expected, game.minimum_set());
+    }
+
+    #[test_case(Set::from([("red", 4), ("green", 2), ("blue", 6)]), 48)]
+    #[test_case(Set::from([("red", 20), ("green", 13), ("blue", 6)]), 1560)]
+    #[test_case(Set::from([("red", 0), ("green", 13), ("blue", 6)]), 0)]
+    fn it_calculates_power(set: Set, expected: u32) {
+        assert_eq!(Some(expected), set.power(&CUBES));
+    }
+
+    #[test_case("3 blue, 4 red", Set::from([("red", 4), ("blue", 3)]), true)]
+    #[test_case("3 blue, 0 red", Set::from([("blue", 3)]), true)]
+    #[test_case("3 blue, 4 red", Set::from([("red", 4)]), false)]
+    #[test_case("3 blue, 4 red, 1 blue", Set::from([("red", 4), ("blue", 1)]), true)]
+    fn sets_compare_by_colour_counts(input: &str, other: Set, should_be_equal: bool) {
+        let set = Set::new(input, &CUBES).unwrap();
+
+        assert_eq!(should_be_equal, set == other);
+    }
+
+    #[test]
+    fn it_parses_unknown_colours_when_permissive() {
+        let palette = Palette {
+            strict: false,
+            ..CUBES
+        };
+        let set = Set::new("3 blue, 4 purple", &palette).unwrap();
+
+        assert_eq!(4, set.count("purple"));
+        assert!(Set::new("3 blue, 4 purple", &CUBES).is_err());
+    }
+
+    #[test]
+    fn it_supports_custom_palettes() {
+        let palette = Palette {
+            colours: &["red", "green", "blue", "yellow"],
+            strict: true,
+        };
+        let game = Game::new(
+            "Game 1: 2 yellow, 1 red; 3 green, 1 blue, 1 yellow",
+            &palette,
+        )
+        .unwrap();
+        let loaded = Set::new("1 red, 3 green, 1 blue, 1 yellow", &palette).unwrap();
+
+        assert!(!game.possible_with(&loaded));
+        assert_eq!(Some(6u32), game.minimum_set().power(&palette));
+        assert_eq!(
+            Some(0u32),
+            game.minimum_set().power(&Palette {
+                colours: &["red", "green", "blue", "yellow", "white"],
+                strict: true,
+            })
+        );
+    }
+
     #[test]
-    fn it_prduces_test_output() {
-        let result = puzzle_3_with_input(PUZZLE_3_TEST_INPUT, "12 red, 13 green, 14 blue").unwrap();
+    fn it_parses_games_lazily() {
+        let mut games = Game::parse_all("Game 1: 3 blue\nGame 2 4 red\nGame 3: 1 red", &CUBES);
+
+        assert_eq!(1, games.next().unwrap().unwrap().id);
+        assert_eq!(Some(2), games.next().unwrap().unwrap_err().line);
+        assert_eq!(3, games.next().unwrap().unwrap().id);
+        assert!(games.next().is_none());
+    }
+
+    /// The totals computed from materialised games, to check [`totals`] against.
+    fn totals_of_games(input: &str, loaded_set: &Set) -> Result<Totals<u32>, ParseError> {
+        let games: Vec<Game> = Game::parse_all(input, &CUBES).collect::<Result<_, _>>()?;
+
+        Ok(Totals {
+            possible_game_ids: games
+                .iter()
+                .filter(|game| game.possible_with(loaded_set))
+                .map(|game| game.id)
+                .sum(),
+            minimum_set_powers: games
+                .iter()
+                .map(|game| game.minimum_set().power::<u32>(&CUBES).unwrap())
+                .sum(),
+        })
+    }
+
+    #[test]
+    fn totals_agree_with_parsed_games() {
+        let Some(input) = puzzle_input(&Day2) else {
+            return;
+        };
+        let loaded_set = Set::new(LOADED_SET, &CUBES).unwrap();
+
+        assert_eq!(
+            totals_of_games(&input, &loaded_set).unwrap(),
+            totals(&input, &CUBES, &loaded_set).unwrap()
+        );
+    }
+
+    #[test_case("Game 1: 1 red, 1 green, 20 blue, 1 blue", 1, 1 ; "after a larger count")]
+    #[test_case("Game 1: 1 red, 1 blue, 1 green, 20 blue", 0, 20 ; "before a larger count")]
+    #[test_case(
+        "Game 1: 2 red, 1 blue; 3 red, 1 green, 0 red\nGame 2: 2 red, 20  red, 1 green, 1 blue",
+        1,
+        22 ;
+        "on several lines"
+    )]
+    fn totals_and_parsed_games_take_the_last_count_of_a_colour(
+        input: &str,
+        possible_game_ids: u32,
+        minimum_set_powers: u32,
+    ) {
+        let loaded_set = Set::new(LOADED_SET, &CUBES).unwrap();
+        let expected = Totals {
+            possible_game_ids,
+            minimum_set_powers,
+        };
+
+        assert_eq!(Ok(expected), totals_of_games(input, &loaded_set));
+        assert_eq!(expected, totals(input, &CUBES, &loaded_set).unwrap());
+    }
+
+    #[test]
+    fn totals_and_parsed_games_allow_colours_in_several_sets() {
+        let input =
+            "Game 1: 1 red, 1 green, 1 blue; 20 blue\nGame 2: 1 blue; 2 blue, 3 red\nGame 3: ;";
+        let loaded_set = Set::new(LOADED_SET, &CUBES).unwrap();
+
+        assert_eq!(
+            Ok(Totals {
+                possible_game_ids: 5,
+                minimum_set_powers: 20,
+            }),
+            totals_of_games(input, &loaded_set)
+        );
+        assert_eq!(
+            totals_of_games(input, &loaded_set).unwrap(),
+            totals(input, &CUBES, &loaded_set).unwrap()
+        );
+    }
+
+    #[test_case(
+        "Game 1: 4 red, 3 blue; 1 red, 2 green, 6 blue; 2 green",
+        "Game 1: 3 blue, 4 red; 6 blue, 2 green, 1 red; 2 green"
+    )]
+    #[test_case("Game 7:  4 red,3 blue ;1  green", "Game 7: 3 blue, 4 red; 1 green")]
+    #[test_case("Game   12: 0 red, 2 purple", "Game 12: 2 purple")]
+    #[test_case("Game 1: 0 red; 2 blue", "Game 1: ; 2 blue")]
+    #[test_case("Game 1:  ", "Game 1: ")]
+    fn it_displays_games_canonically(input: &str, expected: &str) {
+        let game: Game = input.parse().unwrap();
+
+        assert_eq!(expected, game.to_string());
+        assert_eq!(Ok(game), expected.parse());
+    }
+
+    #[test]
+    fn it_displays_sets() {
+        let set = Set::from([("red", 4), ("green", 0), ("blue", 3)]);
+
+        assert_eq!("3 blue, 4 red", set.to_string());
+        assert_eq!(Ok(set), " 4 red,  3 blue".parse());
+    }
+
+    /// Zero counts are dropped, so some sets end up without cubes.
+    fn sets() -> impl Strategy<Value = Set> {
+        prop::collection::btree_map("[a-z]{1,8}", prop_oneof![Just(0), 1..=u32::MAX], 0..5)
+            .prop_map(|cubes| {
+                cubes
+                    .iter()
+                    .fold(Set::default(), |mut set, (colour, count)| {
+                        set.set(colour, *count);
+                        set
+                    })
+            })
+    }
+
+    fn games() -> impl Strategy<Value = Game> {
+        (any::<u32>(), prop::collection::vec(sets(), 1..5)).prop_map(|(id, sets)| Game { id, sets })
+    }
+
+    proptest! {
+        #[test]
+        fn sets_round_trip(set in sets()) {
+            prop_assert_eq!(Ok(set.clone()), set.to_string().parse());
+        }
+
+        #[test]
+        fn games_round_trip(game in games()) {
+            prop_assert_eq!(Ok(game.clone()), game.to_string().parse());
+        }
+    }
+
+    #[test_case("Game 4294967295: 1 red\nGame 4294967295: 1 red", 2, 8_589_934_590, 0)]
+    #[test_case("Game 1: 65536 red, 65536 green, 1 blue", 1, 0, 4_294_967_296)]
+    fn it_reports_overflow(input: &str, line: usize, ids: u64, powers: u64) {
+        let loaded_set = Set::new(LOADED_SET, &CUBES).unwrap();
+        let err = totals::<u32>(input, &CUBES, &loaded_set).unwrap_err();
+
+        assert_eq!(
+            format!("day 2, line {}: answer does not fit into u32", line),
+            err.to_string()
+        );
+        assert_eq!(
+            Totals {
+                possible_game_ids: ids,
+                minimum_set_powers: powers,
+            },
+            totals(input, &CUBES, &loaded_set).unwrap()
+        );
+    }
+
+    #[test]
+    fn parts_report_overflow() {
+        let input = "Game 1: 1 red\nGame 2: 4294967295 red, 4294967295 green, 4294967295 blue";
+        let games = Day2::parse(input).unwrap();
+
+        assert_eq!(Answer::Number(1), Day2::part1(&games).unwrap());
+        assert_eq!(
+            "day 2, line 2: answer does not fit into u64",
+            Day2::part2(&games).unwrap_err().to_string()
+        );
+    }
+
+    #[test_case(
+        "Game 1 3 blue",
+        r#"day 2, line 2: missing ':' after game id in "Game 1 3 blue""#
+    )]
+    #[test_case(
+        "Game x: 3 blue",
+        r#"day 2, line 2, column 6: invalid game id (invalid digit found in string) in "x""#
+    )]
+    #[test_case(
+        "Game 1: 3 blue; 4 pink",
+        r#"day 2, line 2, column 19: unknown colour in "pink""#
+    )]
+    #[test_case(
+        "Game 1: 3 blue, 4red",
+        r#"day 2, line 2, column 17: expected '<count> <colour>' in "4red""#
+    )]
+    #[test_case(
+        "Game 1: 3 blue, , 4 red",
+        r#"day 2, line 2, column 16: expected '<count> <colour>' in """#
+    )]
+    fn it_reports_position_of_bad_input(line: &str, expected: &str) {
+        let input = format!("Game 1: 1 red\n{}", line);
+        let err = Day2::parse(&input).unwrap_err();
 
-        assert_eq!(8, result)
+        assert_eq!(expected, err.to_string());
     }
 }
@@ -0,0 +1,219 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub enum Error {
+    Parse(ParseError),
+    NoSolution {
+        day: u8,
+        part: u8,
+        reason: String,
+    },
+    Io {
+        day: u8,
+        /// `None` when reading from stdin or another stream.
+        path: Option<PathBuf>,
+        source: std::io::Error,
+    },
+    /// A sum or product does not fit into the number type it is computed in.
+    Overflow {
+        day: u8,
+        line: Option<usize>,
+        /// Name of the number type.
+        total: &'static str,
+    },
+    /// The day did not finish within the time limit of the runner.
+    Timeout {
+        day: u8,
+        limit: Duration,
+    },
+    /// The day panicked while the runner was waiting for it.
+    Panic {
+        day: u8,
+        message: String,
+    },
+}
+
+/// A piece of the input that could not be understood. Line and column are 1-based, the
+/// column counts bytes.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new(day: u8, text: impl Into<String>, reason: impl Into<String>) -> ParseError {
+        ParseError {
+            day,
+            line: None,
+            column: None,
+            text: text.into(),
+            reason: reason.into(),
+        }
+    }
+
+    pub fn at_line(mut self, line: usize) -> ParseError {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> ParseError {
+        self.column = Some(column);
+        self
+    }
+
+    /// Moves the column by `offset` bytes, used when the error was found in a slice of a line.
+    pub fn offset_column(mut self, offset: usize) -> ParseError {
+        self.column = self.column.map(|column| column + offset);
+        self
+    }
+}
+
+/// Byte offset of `part` inside `whole`, `part` has to be a slice of `whole`.
+pub fn offset_in(whole: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).wrapping_sub(whole.as_ptr() as usize);
+    debug_assert!(offset <= whole.len(), "part is not a slice of whole");
+    offset
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day {}", self.day)?;
+        if let Some(line) = self.line {
+            write!(f, ", line {}", line)?;
+        }
+        if let Some(column) = self.column {
+            write!(f, ", column {}", column)?;
+        }
+        write!(f, ": {} in {:?}", self.reason, self.text)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse(err) => write!(f, "{}", err),
+            Error::NoSolution { day, part, reason } => {
+                write!(f, "day {}, part {}: no solution: {}", day, part, reason)
+            }
+            Error::Io {
+                day,
+                path: Some(path),
+                source,
+            } => write!(
+                f,
+                "day {}: could not read {}: {}",
+                day,
+                path.display(),
+                source
+            ),
+            Error::Io {
+                day,
+                path: None,
+                source,
+            } => write!(f, "day {}: could not read input: {}", day, source),
+            Error::Overflow { day, line, total } => {
+                write!(f, "day {}", day)?;
+                if let Some(line) = line {
+                    write!(f, ", line {}", line)?;
+                }
+                write!(f, ": answer does not fit into {}", total)
+            }
+            Error::Timeout { day, limit } => {
+                write!(f, "day {}: no answer within {:?}, gave up", day, limit)
+            }
+            Error::Panic { day, message } => write!(f, "day {}: panicked: {}", day, message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Parse(err) => Some(err),
+            Error::NoSolution { .. }
+            | Error::Overflow { .. }
+            | Error::Timeout { .. }
+            | Error::Panic { .. } => None,
+            Error::Io { source, .. } => Some(source),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(value: ParseError) -> Self {
+        Error::Parse(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::ErrorKind;
+
+    #[test]
+    fn it_displays_parse_errors() {
+        let err = ParseError::new(1, "abcxyz", "no digit found").at_line(57);
+
+        assert_eq!(
+            r#"day 1, line 57: no digit found in "abcxyz""#,
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn it_displays_parse_errors_with_column() {
+        let err = ParseError::new(2, "4 pink", "unknown colour")
+            .at_column(3)
+            .offset_column(10)
+            .at_line(2);
+
+        assert_eq!(
+            r#"day 2, line 2, column 13: unknown colour in "4 pink""#,
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn it_displays_io_errors() {
+        let err = Error::Io {
+            day: 3,
+            path: Some(PathBuf::from("inputs/day03.txt")),
+            source: std::io::Error::new(ErrorKind::NotFound, "not found"),
+        };
+
+        assert_eq!(
+            "day 3: could not read inputs/day03.txt: not found",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn it_displays_overflow_errors() {
+        let err = Error::Overflow {
+            day: 2,
+            line: Some(7),
+            total: "u32",
+        };
+
+        assert_eq!(
+            "day 2, line 7: answer does not fit into u32",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn it_finds_offset_of_slices() {
+        let line = "Game 1: 3 blue";
+        let (_, sets) = line.split_once(':').unwrap();
+
+        assert_eq!(7, offset_in(line, sets));
+    }
+}
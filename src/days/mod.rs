@@ -1,3 +1,270 @@
+use crate::days::error::Error;
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+
+pub mod answers;
+pub mod automaton;
 pub mod day1;
 pub mod day2;
+pub mod error;
 pub mod input;
+#[cfg(feature = "swar")]
+pub mod swar;
+pub mod total;
+
+/// Every implemented day, in day order.
+pub static DAYS: &[&dyn Day] = &[&day1::Day1, &day2::Day2];
+
+pub fn find_day(number: u8) -> Option<&'static dyn Day> {
+    DAYS.iter().copied().find(|day| day.number() == number)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Answer {
+    Number(u64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Number(value.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Number(value)
+    }
+}
+
+/// Numbers that do not fit into a `u64` become text.
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        u64::try_from(value).map_or_else(|_| Answer::Text(value.to_string()), Answer::Number)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// An example from the puzzle description together with its expected answer.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Example {
+    pub part: u8,
+    pub input: &'static str,
+    /// Compared with the [`Display`] output of the [`Answer`]. Empty while the answer is not
+    /// known yet, such examples are not checked.
+    pub expected: &'static str,
+}
+
+/// A single day of the calendar. The input is parsed once and shared by both parts.
+pub trait Solver {
+    const DAY: u8;
+    /// Number of parts that are implemented so far.
+    const PARTS: u8 = 2;
+    /// The puzzle input compiled into the binary, see the `embedded-inputs` feature.
+    const EMBEDDED_INPUT: Option<&'static str> = None;
+    /// Checked for every registered day by the tests of this module.
+    const EXAMPLES: &'static [Example] = &[];
+
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error>;
+
+    fn part1(parsed: &Self::Parsed) -> Result<Answer, Error>;
+
+    fn part2(_parsed: &Self::Parsed) -> Result<Answer, Error> {
+        Err(Error::NoSolution {
+            day: Self::DAY,
+            part: 2,
+            reason: "not implemented yet".to_string(),
+        })
+    }
+
+    /// Annotated view of how the answers come about, printed by `--explain`.
+    fn explain(_parsed: &Self::Parsed) -> Option<String> {
+        None
+    }
+}
+
+/// Object safe view on a [`Solver`] so that all days can live in [`DAYS`].
+pub trait Day: Sync {
+    fn number(&self) -> u8;
+
+    fn parts(&self) -> u8;
+
+    fn embedded_input(&self) -> Option<&'static str>;
+
+    fn examples(&self) -> &'static [Example];
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, Error>;
+
+    fn solve(&self, parsed: &dyn Any, part: u8) -> Result<Answer, Error>;
+
+    /// `None` if the day has nothing to explain.
+    fn explain(&self, input: &str) -> Result<Option<String>, Error>;
+
+    fn run(&self, input: &str, part: u8) -> Result<Answer, Error> {
+        let parsed = self.parse(input)?;
+        self.solve(parsed.as_ref(), part)
+    }
+}
+
+impl<S> Day for S
+where
+    S: Solver + Sync,
+    S::Parsed: 'static,
+{
+    fn number(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parts(&self) -> u8 {
+        S::PARTS
+    }
+
+    fn embedded_input(&self) -> Option<&'static str> {
+        S::EMBEDDED_INPUT
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        S::EXAMPLES
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, Error> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve(&self, parsed: &dyn Any, part: u8) -> Result<Answer, Error> {
+        let parsed = parsed
+            .downcast_ref::<S::Parsed>()
+            .ok_or_else(|| Error::NoSolution {
+                day: S::DAY,
+                part,
+                reason: "parsed input belongs to another day".to_string(),
+            })?;
+
+        match part {
+            1 => S::part1(parsed),
+            2 if part <= S::PARTS => S::part2(parsed),
+            _ => Err(Error::NoSolution {
+                day: S::DAY,
+                part,
+                reason: "part does not exist".to_string(),
+            }),
+        }
+    }
+
+    fn explain(&self, input: &str) -> Result<Option<String>, Error> {
+        Ok(S::explain(&S::parse(input)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_registered_in_order() {
+        let numbers: Vec<u8> = DAYS.iter().map(|day| day.number()).collect();
+        let mut sorted = numbers.clone();
+        sorted.sort();
+        sorted.dedup();
+
+        assert_eq!(sorted, numbers);
+    }
+
+    #[test]
+    fn every_part_has_an_example() {
+        for day in DAYS {
+            for part in 1..=day.parts() {
+                assert!(
+                    day.examples().iter().any(|example| example.part == part),
+                    "day {} part {} has no example",
+                    day.number(),
+                    part
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn examples_produce_expected_answers() {
+        for day in DAYS {
+            for example in day
+                .examples()
+                .iter()
+                .filter(|example| !example.expected.is_empty())
+            {
+                let answer = day
+                    .run(example.input, example.part)
+                    .unwrap_or_else(|err| panic!("{} on example {:?}", err, example.input));
+
+                assert_eq!(
+                    example.expected,
+                    answer.to_string(),
+                    "day {} part {} on example {:?}",
+                    day.number(),
+                    example.part,
+                    example.input
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn it_keeps_wide_answers_exact() {
+        assert_eq!(Answer::Number(7), Answer::from(7u128));
+        assert_eq!(
+            "18446744073709551616",
+            Answer::from(u128::from(u64::MAX) + 1).to_string()
+        );
+    }
+
+    #[test]
+    fn it_finds_registered_days() {
+        assert_eq!(Some(2), find_day(2).map(|day| day.number()));
+        assert!(find_day(0).is_none());
+    }
+
+    #[test]
+    fn it_rejects_parsed_input_of_other_days() {
+        let day1 = find_day(1).unwrap();
+        let day2 = find_day(2).unwrap();
+        let parsed = day2.parse("").unwrap();
+
+        assert!(day1.solve(parsed.as_ref(), 1).is_err());
+    }
+
+    #[test]
+    fn it_rejects_unknown_parts() {
+        let day1 = find_day(1).unwrap();
+
+        assert!(matches!(
+            day1.run("1abc2", 3),
+            Err(Error::NoSolution {
+                day: 1,
+                part: 3,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,100 @@
+//! Scanning for ASCII digits and line breaks eight bytes at a time (SIMD within a register).
+
+const WORD: usize = std::mem::size_of::<u64>();
+const LOW_BITS: u64 = 0x7F7F_7F7F_7F7F_7F7F;
+const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+
+const fn splat(byte: u8) -> u64 {
+    u64::from_ne_bytes([byte; WORD])
+}
+
+/// Sets the high bit of every byte of `word` that is an ASCII digit.
+fn digits(word: u64) -> u64 {
+    // The low seven bits of a byte plus at most 0x50 never carry into the next byte.
+    let low = word & LOW_BITS;
+    let at_least_zero = low + splat(0x80 - b'0');
+    let above_nine = low + splat(0x7F - b'9');
+
+    at_least_zero & !above_nine & !word & HIGH_BITS
+}
+
+/// Sets the high bit of every byte of `word` that equals `byte`.
+fn bytes_equal_to(word: u64, byte: u8) -> u64 {
+    let diff = word ^ splat(byte);
+
+    !(((diff & LOW_BITS) + LOW_BITS) | diff | LOW_BITS)
+}
+
+/// Sum of the number formed by the first and last ASCII digit of every line, the same as
+/// part 1 of day 1 with [`DigitPolicy::Ascii`](crate::days::day1::DigitPolicy::Ascii).
+///
+/// Returns `None` if a line has no digit or the sum overflows, the caller is expected to
+/// fall back to the scalar implementation to report that.
+pub fn sum_first_and_last_digits(input: &[u8]) -> Option<u32> {
+    let mut sum: u32 = 0;
+    let mut first: Option<u8> = None;
+    let mut last = 0;
+    let mut line_start = 0;
+
+    for (index, chunk) in input.chunks(WORD).enumerate() {
+        // Padding with zeros is fine, they are neither digits nor line breaks.
+        let mut bytes = [0; WORD];
+        bytes[..chunk.len()].copy_from_slice(chunk);
+        let word = u64::from_le_bytes(bytes);
+
+        let mut events = digits(word) | bytes_equal_to(word, b'\n');
+        while events != 0 {
+            let offset = events.trailing_zeros() as usize / 8;
+            events &= events - 1;
+
+            match chunk[offset] {
+                b'\n' => {
+                    sum = sum.checked_add(u32::from(first? * 10 + last))?;
+                    first = None;
+                    line_start = index * WORD + offset + 1;
+                }
+                digit => {
+                    first.get_or_insert(digit - b'0');
+                    last = digit - b'0';
+                }
+            }
+        }
+    }
+
+    // Like `str::lines`, the text after the last line break only is a line if it is not empty.
+    if line_start < input.len() {
+        sum = sum.checked_add(u32::from(first? * 10 + last))?;
+    }
+
+    Some(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(b"01234567", 0x8080_8080_8080_8080)]
+    #[test_case(b"/:09\xb0\xb9ab", 0x0000_0000_8080_0000)]
+    #[test_case(b"\n\0\x7f\xff89\n\n", 0x0000_8080_0000_0000)]
+    fn it_finds_digits(bytes: &[u8; WORD], expected: u64) {
+        assert_eq!(expected, digits(u64::from_le_bytes(*bytes)));
+    }
+
+    #[test_case(b"\na\n\x0b\x8a\n\x09\n", 0x8000_8000_0080_0080 ; "mixed")]
+    #[test_case(b"\0\0\0\0\0\0\0\0", 0 ; "zeros")]
+    fn it_finds_line_breaks(bytes: &[u8; WORD], expected: u64) {
+        assert_eq!(expected, bytes_equal_to(u64::from_le_bytes(*bytes), b'\n'));
+    }
+
+    #[test_case("1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet", Some(142) ; "example")]
+    #[test_case("1abc2\r\npqr3stu8vwx\r\n", Some(50) ; "crlf")]
+    #[test_case("xxxxxxxxxxxxxx7xxxxxxxxxxxxxxxxx\n", Some(77) ; "long line")]
+    #[test_case("", Some(0) ; "empty input")]
+    #[test_case("1\n\n2", None ; "empty line")]
+    #[test_case("12\nabc", None ; "missing digit")]
+    #[test_case("١٢\n3", None ; "non ascii digits")]
+    fn it_sums_first_and_last_digits(input: &str, expected: Option<u32>) {
+        assert_eq!(expected, sum_first_and_last_digits(input.as_bytes()));
+    }
+}
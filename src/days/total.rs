@@ -0,0 +1,50 @@
+use crate::days::Answer;
+use std::fmt::Debug;
+
+/// Number type sums and products are computed in. Every operation is checked, so a type that
+/// is too narrow for the input gives an [`Error::Overflow`](crate::days::error::Error) instead
+/// of a wrong answer.
+pub trait Total: Copy + Default + Debug + Eq + From<u32> + Into<Answer> {
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// Name of the type, used in error messages.
+    fn name() -> &'static str {
+        std::any::type_name::<Self>()
+    }
+}
+
+macro_rules! impl_total {
+    ($($ty:ty),*) => {
+        $(
+            impl Total for $ty {
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$ty>::checked_add(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$ty>::checked_mul(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_total!(u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_detects_overflow() {
+        assert_eq!(None, Total::checked_add(u32::MAX, 1));
+        assert_eq!(
+            Some(u64::from(u32::MAX) + 1),
+            Total::checked_add(u64::from(u32::MAX), 1)
+        );
+        assert_eq!(None, Total::checked_mul(u64::MAX, 2));
+        assert_eq!("u128", u128::name());
+    }
+}
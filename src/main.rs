@@ -1,19 +1,104 @@
-#![feature(test)]
-extern crate test;
+use crate::cli::{Command, Format, USAGE};
+use crate::output::Row;
+use crate::runner::{explain_days, print_timings, run_days};
+use advent_of_code::days::answers::Answers;
+use advent_of_code::days::DAYS;
+use std::process::ExitCode;
 
-use crate::days::day1::{puzzle1, puzzle2};
-use crate::days::day2::puzzle3;
+mod cli;
+mod output;
+mod runner;
+mod scaffold;
 
-#[allow(dead_code)]
-pub mod days;
+fn main() -> ExitCode {
+    let command = match Command::parse(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err_msg) => {
+            eprintln!("{}\n\n{}", err_msg, USAGE);
+            return ExitCode::from(2);
+        }
+    };
 
-fn main() {
-    let val1 = puzzle1().map_or_else(|err_msg| err_msg.to_string(), |val| val.to_string());
-    println!("Puzzle 1 answer: {}", val1);
+    match command {
+        Command::Help => {
+            println!("{}", USAGE);
+            ExitCode::SUCCESS
+        }
+        Command::NewDay { day, root } => match scaffold::new_day(&root, day) {
+            Ok(files) => {
+                for file in files {
+                    println!("Wrote {}", file.display());
+                }
+                ExitCode::SUCCESS
+            }
+            Err(err_msg) => {
+                eprintln!("Error: {}", err_msg);
+                ExitCode::FAILURE
+            }
+        },
+        Command::List => {
+            for day in DAYS {
+                for part in 1..=day.parts() {
+                    println!("Day {} part {}", day.number(), part);
+                }
+            }
+            ExitCode::SUCCESS
+        }
+        Command::Run(run) => {
+            let answers = if run.verify {
+                match Answers::load() {
+                    Ok(answers) => Some(answers),
+                    Err(err_msg) => {
+                        eprintln!("Error: {}", err_msg);
+                        return ExitCode::FAILURE;
+                    }
+                }
+            } else {
+                None
+            };
+            let days: Vec<_> = DAYS
+                .iter()
+                .map(|day| {
+                    let parts: Vec<u8> = (1..=day.parts())
+                        .filter(|part| run.selection.matches(day.number(), *part))
+                        .collect();
+                    (*day, parts)
+                })
+                .filter(|(_, parts)| !parts.is_empty())
+                .collect();
 
-    let val2 = puzzle2().map_or_else(|err_msg| err_msg.to_string(), |val| val.to_string());
-    println!("Puzzle 2 answer: {}", val2);
+            if days.is_empty() {
+                eprintln!("No puzzle registered for the given selection");
+                return ExitCode::FAILURE;
+            }
 
-    let val3 = puzzle3().map_or_else(|err_msg| err_msg.to_string(), |val| val.to_string());
-    println!("Puzzle 3 answer: {}", val3);
+            if run.explain {
+                return if explain_days(&days, &run) {
+                    ExitCode::SUCCESS
+                } else {
+                    ExitCode::FAILURE
+                };
+            }
+
+            let reports = run_days(&days, &run);
+            let rows = output::rows(&reports, answers.as_ref());
+
+            match run.format {
+                Format::Text => {
+                    output::print_text(&rows);
+                    if run.time {
+                        print_timings(&reports);
+                    }
+                }
+                Format::Json => print!("{}", output::json(&rows)),
+                Format::Csv => print!("{}", output::csv(&rows)),
+            }
+
+            if rows.iter().any(Row::failed) {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+    }
 }
@@ -0,0 +1,304 @@
+use crate::runner::{DayReport, Timing};
+use advent_of_code::days::answers::{Answers, Verdict};
+
+const CSV_HEADER: &str = "day,part,answer,error,verdict,expected,runs,median_ns,min_ns";
+
+/// One phase of a day, flattened for printing.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Row {
+    pub day: u8,
+    /// `None` for loading and parsing the input.
+    pub part: Option<u8>,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    /// Only set when the answers are verified.
+    pub verdict: Option<Verdict>,
+    pub timing: Option<Timing>,
+}
+
+impl Row {
+    pub fn failed(&self) -> bool {
+        self.error.is_some() || matches!(self.verdict, Some(Verdict::Fail { .. }))
+    }
+
+    fn verdict_name(&self) -> Option<&'static str> {
+        self.verdict.as_ref().map(|verdict| match verdict {
+            Verdict::Pass => "pass",
+            Verdict::Fail { .. } => "fail",
+            Verdict::Unknown => "unknown",
+        })
+    }
+
+    fn expected(&self) -> Option<String> {
+        match &self.verdict {
+            Some(Verdict::Fail { expected }) => Some(expected.to_string()),
+            _ => None,
+        }
+    }
+}
+
+pub fn rows(reports: &[DayReport], answers: Option<&Answers>) -> Vec<Row> {
+    let mut rows = Vec::new();
+
+    for report in reports {
+        rows.push(Row {
+            day: report.day,
+            part: None,
+            answer: None,
+            error: report.parse.as_ref().err().map(ToString::to_string),
+            verdict: None,
+            timing: report.parse.as_ref().ok().copied(),
+        });
+
+        for part in report.parts.iter() {
+            rows.push(Row {
+                day: report.day,
+                part: Some(part.part),
+                answer: part.answer.as_ref().ok().map(ToString::to_string),
+                error: part.answer.as_ref().err().map(ToString::to_string),
+                verdict: answers.and_then(|answers| {
+                    let answer = part.answer.as_ref().ok()?;
+                    Some(answers.check(report.day, part.part, answer))
+                }),
+                timing: Some(part.timing),
+            });
+        }
+    }
+
+    rows
+}
+
+/// Answers go to stdout, errors to stderr.
+pub fn print_text(rows: &[Row]) {
+    for row in rows {
+        if let Some(err) = &row.error {
+            eprintln!("Error: {}", err);
+        }
+        let (Some(part), Some(answer)) = (row.part, &row.answer) else {
+            continue;
+        };
+        match &row.verdict {
+            None => println!("Day {} part {}: {}", row.day, part, answer),
+            Some(verdict) => println!("Day {} part {}: {} ({})", row.day, part, answer, verdict),
+        }
+    }
+}
+
+/// An array with one object per row, missing values are `null`.
+pub fn json(rows: &[Row]) -> String {
+    let objects: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            let fields = [
+                ("day", row.day.to_string()),
+                ("part", json_value(row.part)),
+                ("answer", json_string(row.answer.as_deref())),
+                ("error", json_string(row.error.as_deref())),
+                ("verdict", json_string(row.verdict_name())),
+                ("expected", json_string(row.expected().as_deref())),
+                ("runs", json_value(row.timing.map(|timing| timing.runs))),
+                (
+                    "median_ns",
+                    json_value(row.timing.map(|timing| timing.median.as_nanos())),
+                ),
+                (
+                    "min_ns",
+                    json_value(row.timing.map(|timing| timing.min.as_nanos())),
+                ),
+            ];
+            let fields: Vec<String> = fields
+                .iter()
+                .map(|(key, value)| format!("\"{}\": {}", key, value))
+                .collect();
+            format!("  {{{}}}", fields.join(", "))
+        })
+        .collect();
+
+    if objects.is_empty() {
+        "[]\n".to_string()
+    } else {
+        format!("[\n{}\n]\n", objects.join(",\n"))
+    }
+}
+
+/// A header line and one line per row, missing values are empty.
+pub fn csv(rows: &[Row]) -> String {
+    let mut output = format!("{}\n", CSV_HEADER);
+
+    for row in rows {
+        let fields = [
+            row.day.to_string(),
+            csv_value(row.part),
+            csv_string(row.answer.as_deref()),
+            csv_string(row.error.as_deref()),
+            csv_string(row.verdict_name()),
+            csv_string(row.expected().as_deref()),
+            csv_value(row.timing.map(|timing| timing.runs)),
+            csv_value(row.timing.map(|timing| timing.median.as_nanos())),
+            csv_value(row.timing.map(|timing| timing.min.as_nanos())),
+        ];
+        output.push_str(&fields.join(","));
+        output.push('\n');
+    }
+
+    output
+}
+
+fn json_value<T: ToString>(value: Option<T>) -> String {
+    value.map_or("null".to_string(), |value| value.to_string())
+}
+
+fn json_string(value: Option<&str>) -> String {
+    let Some(value) = value else {
+        return "null".to_string();
+    };
+
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn csv_value<T: ToString>(value: Option<T>) -> String {
+    value.map_or(String::new(), |value| value.to_string())
+}
+
+/// Quotes the value if it contains a separator, a quote or a line break.
+fn csv_string(value: Option<&str>) -> String {
+    match value {
+        Some(value) if value.contains([',', '"', '\n', '\r']) => {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        }
+        Some(value) => value.to_string(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::PartReport;
+    use advent_of_code::days::error::Error;
+    use advent_of_code::days::Answer;
+    use std::time::Duration;
+
+    fn timing(nanos: u64) -> Timing {
+        Timing {
+            runs: 1,
+            median: Duration::from_nanos(nanos),
+            min: Duration::from_nanos(nanos),
+        }
+    }
+
+    fn reports() -> Vec<DayReport> {
+        vec![
+            DayReport {
+                day: 1,
+                parse: Ok(timing(10)),
+                parts: vec![
+                    PartReport {
+                        part: 1,
+                        answer: Ok(Answer::from(142u32)),
+                        timing: timing(20),
+                    },
+                    PartReport {
+                        part: 2,
+                        answer: Err(Error::NoSolution {
+                            day: 1,
+                            part: 2,
+                            reason: "not \"implemented\", yet".to_string(),
+                        }),
+                        timing: timing(30),
+                    },
+                ],
+            },
+            DayReport {
+                day: 2,
+                parse: Err(Error::Timeout {
+                    day: 2,
+                    limit: Duration::from_secs(1),
+                }),
+                parts: Vec::new(),
+            },
+        ]
+    }
+
+    #[test]
+    fn it_flattens_reports() {
+        let rows = rows(&reports(), None);
+
+        assert_eq!(4, rows.len());
+        assert_eq!((1, None), (rows[0].day, rows[0].part));
+        assert_eq!(Some("142".to_string()), rows[1].answer);
+        assert!(!rows[1].failed());
+        assert!(rows[2].failed());
+        assert!(rows[3].failed());
+        assert_eq!(None, rows[3].timing);
+    }
+
+    #[test]
+    fn it_verifies_answers() {
+        let answers = Answers::parse("[day01]\npart1 = 141").unwrap();
+        let rows = rows(&reports(), Some(&answers));
+
+        assert!(rows[1].failed());
+        assert_eq!(Some("fail"), rows[1].verdict_name());
+        assert_eq!(Some("141".to_string()), rows[1].expected());
+        assert_eq!(None, rows[2].verdict);
+    }
+
+    #[test]
+    fn it_writes_json() {
+        let json = json(&rows(&reports(), None));
+        let lines: Vec<&str> = json.lines().collect();
+
+        assert_eq!("[", lines[0]);
+        assert_eq!(
+            r#"  {"day": 1, "part": 1, "answer": "142", "error": null, "verdict": null, "expected": null, "runs": 1, "median_ns": 20, "min_ns": 20},"#,
+            lines[2]
+        );
+        assert!(
+            lines[3].contains(r#""error": "day 1, part 2: no solution: not \"implemented\", yet""#)
+        );
+        assert!(lines[4].contains(r#""part": null"#));
+        assert_eq!("]", lines[5]);
+    }
+
+    #[test]
+    fn it_writes_csv() {
+        let csv = csv(&rows(&reports(), None));
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(CSV_HEADER, lines[0]);
+        assert_eq!("1,,,,,,1,10,10", lines[1]);
+        assert_eq!("1,1,142,,,,1,20,20", lines[2]);
+        assert_eq!(
+            r#"1,2,,"day 1, part 2: no solution: not ""implemented"", yet",,,1,30,30"#,
+            lines[3]
+        );
+        assert_eq!(
+            r#"2,,,"day 2: no answer within 1s, gave up",,,,,"#,
+            lines[4]
+        );
+    }
+
+    #[test]
+    fn it_escapes_json_strings() {
+        assert_eq!(
+            r#""a\"b\\c\nd\u0001""#,
+            json_string(Some("a\"b\\c\nd\u{1}"))
+        );
+        assert_eq!("null", json_string(None));
+    }
+}
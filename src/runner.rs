@@ -0,0 +1,418 @@
+use crate::cli::Run;
+use advent_of_code::days::error::Error;
+use advent_of_code::days::input::{load, InputSource};
+use advent_of_code::days::{Answer, Day};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub struct DayReport {
+    pub day: u8,
+    /// Timing of the parse phase, or why the input could not be loaded or parsed. Parts with
+    /// inputs of their own add up their parse timings.
+    pub parse: Result<Timing, Error>,
+    pub parts: Vec<PartReport>,
+}
+
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Result<Answer, Error>,
+    pub timing: Timing,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Timing {
+    pub runs: usize,
+    pub median: Duration,
+    pub min: Duration,
+}
+
+impl Timing {
+    fn plus(self, other: Timing) -> Timing {
+        Timing {
+            runs: self.runs,
+            median: self.median + other.median,
+            min: self.min + other.min,
+        }
+    }
+
+    fn from_samples(mut samples: Vec<Duration>) -> Timing {
+        samples.sort();
+
+        Timing {
+            runs: samples.len(),
+            median: samples[samples.len() / 2],
+            min: samples[0],
+        }
+    }
+}
+
+/// Runs `f` `repeat` times and returns the last result.
+fn measure<T>(repeat: usize, mut f: impl FnMut() -> T) -> (T, Timing) {
+    let mut samples = Vec::with_capacity(repeat);
+    let mut result = None;
+
+    for _ in 0..repeat.max(1) {
+        let start = Instant::now();
+        result = Some(f());
+        samples.push(start.elapsed());
+    }
+
+    (result.unwrap(), Timing::from_samples(samples))
+}
+
+pub fn run_day(day: &dyn Day, parts: &[u8], run: &Run) -> DayReport {
+    match run_parts(day, parts, run) {
+        Ok((parse, parts)) => DayReport {
+            day: day.number(),
+            parse: Ok(parse),
+            parts,
+        },
+        Err(err) => DayReport {
+            day: day.number(),
+            parse: Err(err),
+            parts: Vec::new(),
+        },
+    }
+}
+
+/// Loads and parses every input once, parts that read the same input share it.
+fn run_parts(day: &dyn Day, parts: &[u8], run: &Run) -> Result<(Timing, Vec<PartReport>), Error> {
+    let mut sources: Vec<(InputSource, Vec<u8>)> = Vec::new();
+    for part in parts {
+        let source = run.input.for_part(day.number(), *part);
+        match sources.iter_mut().find(|(known, _)| *known == source) {
+            Some((_, parts)) => parts.push(*part),
+            None => sources.push((source, vec![*part])),
+        }
+    }
+    if sources.is_empty() {
+        sources.push((run.input.clone(), Vec::new()));
+    }
+
+    let mut parse_timing: Option<Timing> = None;
+    let mut reports = Vec::new();
+    for (source, parts) in sources {
+        let input = load(day, &source)?;
+        let (parsed, timing) = measure(run.repeat, || day.parse(&input));
+        let parsed = parsed?;
+        parse_timing = Some(parse_timing.map_or(timing, |total| total.plus(timing)));
+
+        reports.extend(parts.iter().map(|part| {
+            let (answer, timing) = measure(run.repeat, || day.solve(parsed.as_ref(), *part));
+            PartReport {
+                part: *part,
+                answer,
+                timing,
+            }
+        }));
+    }
+    reports.sort_by_key(|report| report.part);
+
+    Ok((parse_timing.expect("at least one input"), reports))
+}
+
+/// Runs the given days on `run.jobs` worker threads and returns their reports in day order.
+pub fn run_days(days: &[(&'static dyn Day, Vec<u8>)], run: &Run) -> Vec<DayReport> {
+    let run = Arc::new(run.clone());
+    let next = AtomicUsize::new(0);
+
+    let mut reports: Vec<DayReport> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..run.jobs.clamp(1, days.len().max(1)))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut reports = Vec::new();
+                    while let Some((day, parts)) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        reports.push(run_day_with_timeout(*day, parts.clone(), &run));
+                    }
+                    reports
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("worker thread panicked"))
+            .collect()
+    });
+
+    reports.sort_by_key(|report| report.day);
+    reports
+}
+
+/// Runs the day on its own thread so the caller can stop waiting after `run.timeout`. A day
+/// that times out keeps running in the background until the process exits.
+fn run_day_with_timeout(day: &'static dyn Day, parts: Vec<u8>, run: &Arc<Run>) -> DayReport {
+    let Some(limit) = run.timeout else {
+        return run_day_catching_panics(day, &parts, run);
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let run = Arc::clone(run);
+    thread::spawn(move || {
+        // The receiver is gone when the day took too long.
+        let _ = sender.send(run_day_catching_panics(day, &parts, &run));
+    });
+
+    match receiver.recv_timeout(limit) {
+        Ok(report) => report,
+        Err(RecvTimeoutError::Timeout) => DayReport {
+            day: day.number(),
+            parse: Err(Error::Timeout {
+                day: day.number(),
+                limit,
+            }),
+            parts: Vec::new(),
+        },
+        Err(RecvTimeoutError::Disconnected) => {
+            panicked(day, "stopped without a report".to_string())
+        }
+    }
+}
+
+/// Like [`run_day`], but a panicking day becomes an error instead of taking the runner down.
+fn run_day_catching_panics(day: &dyn Day, parts: &[u8], run: &Run) -> DayReport {
+    panic::catch_unwind(AssertUnwindSafe(|| run_day(day, parts, run))).unwrap_or_else(|payload| {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => payload
+                .downcast_ref::<&str>()
+                .map_or("unknown cause", |message| message)
+                .to_string(),
+        };
+        panicked(day, message)
+    })
+}
+
+fn panicked(day: &dyn Day, message: String) -> DayReport {
+    DayReport {
+        day: day.number(),
+        parse: Err(Error::Panic {
+            day: day.number(),
+            message,
+        }),
+        parts: Vec::new(),
+    }
+}
+
+/// Prints the explanation of every day and returns whether all inputs could be read.
+pub fn explain_days(days: &[(&'static dyn Day, Vec<u8>)], run: &Run) -> bool {
+    let mut ok = true;
+
+    for (day, _) in days {
+        match load(*day, &run.input).and_then(|input| day.explain(&input)) {
+            Ok(Some(explanation)) => print!("Day {}\n{}", day.number(), explanation),
+            Ok(None) => eprintln!("Day {} has nothing to explain", day.number()),
+            Err(err) => {
+                ok = false;
+                eprintln!("Error: {}", err);
+            }
+        }
+    }
+
+    ok
+}
+
+pub fn print_timings(reports: &[DayReport]) {
+    println!();
+    println!(
+        "{:>3}  {:<6}  {:>5}  {:>12}  {:>12}",
+        "Day", "Phase", "Runs", "Median", "Min"
+    );
+
+    let mut total = Duration::ZERO;
+    for report in reports {
+        let Ok(parse) = &report.parse else {
+            continue;
+        };
+        let phases = std::iter::once(("parse".to_string(), parse)).chain(
+            report
+                .parts
+                .iter()
+                .map(|part| (format!("part {}", part.part), &part.timing)),
+        );
+
+        for (phase, timing) in phases {
+            total += timing.median;
+            println!(
+                "{:>3}  {:<6}  {:>5}  {:>12}  {:>12}",
+                report.day,
+                phase,
+                timing.runs,
+                format!("{:.1?}", timing.median),
+                format!("{:.1?}", timing.min)
+            );
+        }
+    }
+
+    println!("Total of medians: {:.1?}", total);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::days::{find_day, Solver};
+    use std::sync::Mutex;
+    use test_case::test_case;
+
+    /// Answers its day number after sleeping for `MILLIS` milliseconds.
+    struct Sleepy<const DAY: u8, const MILLIS: u64>;
+
+    impl<const DAY: u8, const MILLIS: u64> Solver for Sleepy<DAY, MILLIS> {
+        const DAY: u8 = DAY;
+        const PARTS: u8 = 1;
+
+        type Parsed = ();
+
+        fn parse(_input: &str) -> Result<Self::Parsed, Error> {
+            thread::sleep(Duration::from_millis(MILLIS));
+            Ok(())
+        }
+
+        fn part1(_parsed: &Self::Parsed) -> Result<Answer, Error> {
+            Ok(Answer::from(u32::from(DAY)))
+        }
+    }
+
+    /// Panics while parsing.
+    struct Panicky;
+
+    impl Solver for Panicky {
+        const DAY: u8 = 1;
+
+        type Parsed = ();
+
+        fn parse(_input: &str) -> Result<Self::Parsed, Error> {
+            panic!("bad day");
+        }
+
+        fn part1(_parsed: &Self::Parsed) -> Result<Answer, Error> {
+            unreachable!()
+        }
+    }
+
+    /// Parses only once the test drops the sender of the receiver in [`BLOCKED`].
+    struct Blocked;
+
+    static BLOCKED: Mutex<Option<mpsc::Receiver<()>>> = Mutex::new(None);
+
+    impl Solver for Blocked {
+        const DAY: u8 = 1;
+        const PARTS: u8 = 1;
+
+        type Parsed = ();
+
+        fn parse(_input: &str) -> Result<Self::Parsed, Error> {
+            let receiver = BLOCKED.lock().unwrap().take();
+            if let Some(receiver) = receiver {
+                let _ = receiver.recv();
+            }
+            Ok(())
+        }
+
+        fn part1(_parsed: &Self::Parsed) -> Result<Answer, Error> {
+            Ok(Answer::from(1u32))
+        }
+    }
+
+    fn parallel_run(jobs: usize, timeout: Option<Duration>) -> Run {
+        Run {
+            input: InputSource::Path(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").into()),
+            repeat: 1,
+            jobs,
+            timeout,
+            ..Run::default()
+        }
+    }
+
+    #[test]
+    fn it_calculates_median_and_min() {
+        let samples = [5, 1, 4, 2, 3].map(Duration::from_millis).to_vec();
+        let timing = Timing::from_samples(samples);
+
+        assert_eq!(5, timing.runs);
+        assert_eq!(Duration::from_millis(3), timing.median);
+        assert_eq!(Duration::from_millis(1), timing.min);
+    }
+
+    #[test]
+    fn it_measures_every_run() {
+        let mut calls = 0;
+        let (result, timing) = measure(4, || {
+            calls += 1;
+            calls
+        });
+
+        assert_eq!(4, result);
+        assert_eq!(4, timing.runs);
+    }
+
+    #[test]
+    fn it_reports_days_in_day_order() {
+        let days: [(&'static dyn Day, Vec<u8>); 3] = [
+            (&Sleepy::<3, 0>, vec![1]),
+            (&Sleepy::<1, 100>, vec![1]),
+            (&Sleepy::<2, 0>, vec![1]),
+        ];
+        let reports = run_days(&days, &parallel_run(3, None));
+
+        let answers: Vec<(u8, Answer)> = reports
+            .into_iter()
+            .flat_map(|report| report.parts.into_iter().map(move |part| (report.day, part)))
+            .map(|(day, part)| (day, part.answer.unwrap()))
+            .collect();
+        assert_eq!(
+            vec![(1, Answer::from(1u32)), (2, 2u32.into()), (3, 3u32.into())],
+            answers
+        );
+    }
+
+    #[test]
+    fn parts_read_examples_of_their_own() {
+        let run = Run {
+            input: InputSource::Example,
+            ..Run::default()
+        };
+        let report = run_day(find_day(1).unwrap(), &[1, 2], &run);
+
+        let answers: Vec<Answer> = report
+            .parts
+            .into_iter()
+            .map(|part| part.answer.unwrap())
+            .collect();
+        assert_eq!(vec![Answer::from(142u32), 281u32.into()], answers);
+    }
+
+    #[test]
+    fn it_gives_up_on_slow_days() {
+        let (release, receiver) = mpsc::channel();
+        *BLOCKED.lock().unwrap() = Some(receiver);
+        let days: [(&'static dyn Day, Vec<u8>); 2] =
+            [(&Blocked, vec![1]), (&Sleepy::<2, 0>, vec![1])];
+        let reports = run_days(&days, &parallel_run(2, Some(Duration::from_millis(50))));
+        drop(release);
+
+        assert!(matches!(
+            reports[0].parse,
+            Err(Error::Timeout { day: 1, .. })
+        ));
+        assert!(reports[1].parts[0].answer.is_ok());
+    }
+
+    #[test_case(None)]
+    #[test_case(Some(Duration::from_secs(10)))]
+    fn it_reports_panicking_days(timeout: Option<Duration>) {
+        let days: [(&'static dyn Day, Vec<u8>); 2] =
+            [(&Panicky, vec![1]), (&Sleepy::<2, 0>, vec![1])];
+        let reports = run_days(&days, &parallel_run(2, timeout));
+
+        match &reports[0].parse {
+            Err(Error::Panic { day: 1, message }) => assert_eq!("bad day", message),
+            other => panic!("expected a panic, got {:?}", other.as_ref().err()),
+        }
+        assert!(reports[1].parts[0].answer.is_ok());
+    }
+}
@@ -0,0 +1,228 @@
+use std::path::{Path, PathBuf};
+
+const DAYS_MOD: &str = "src/days/mod.rs";
+const REGISTRY_START: &str = "pub static DAYS: &[&dyn Day] = &[";
+const MAX_WIDTH: usize = 100;
+
+const TEMPLATE: &str = r#"use crate::days::error::Error;
+use crate::days::{Answer, Example, Solver};
+
+pub struct Day__DAY__;
+
+impl Solver for Day__DAY__ {
+    const DAY: u8 = __DAY__;
+    // Raise to 2 once part 1 is solved.
+    const PARTS: u8 = 1;
+    #[cfg(feature = "embedded-inputs")]
+    const EMBEDDED_INPUT: Option<&'static str> = Some(include_str!("../../inputs/day__NN__.txt"));
+    const EXAMPLES: &'static [Example] = &[Example {
+        part: 1,
+        input: include_str!("../../inputs/day__NN__.example.txt"),
+        expected: "",
+    }];
+
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(_parsed: &Self::Parsed) -> Result<Answer, Error> {
+        Err(Error::NoSolution {
+            day: Self::DAY,
+            part: 1,
+            reason: "not implemented yet".to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_example() {
+        assert!(Day__DAY__::parse(Day__DAY__::EXAMPLES[0].input).is_ok());
+    }
+}
+"#;
+
+/// Writes the module, input files and registry entry of a new day below `root`, the
+/// directory containing `Cargo.toml`. Returns the created files.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !root.join("Cargo.toml").exists() {
+        return Err(format!(
+            "No Cargo.toml in {}, run new-day in the crate or pass --root",
+            root.display()
+        ));
+    }
+
+    let module = root.join(format!("src/days/day{}.rs", day));
+    let input = root.join(format!("inputs/day{:02}.txt", day));
+    let example = root.join(format!("inputs/day{:02}.example.txt", day));
+    let days_mod = root.join(DAYS_MOD);
+
+    for path in [&module, &input, &example] {
+        if path.exists() {
+            return Err(format!("Day {} already exists: {}", day, path.display()));
+        }
+    }
+
+    let registry = std::fs::read_to_string(&days_mod)
+        .map_err(|err| format!("Could not read {}: {}", days_mod.display(), err))?;
+    let registry = register(&registry, day)?;
+
+    let module_source = TEMPLATE
+        .replace("__DAY__", &day.to_string())
+        .replace("__NN__", &format!("{:02}", day));
+
+    let write = |path: &Path, content: &str| {
+        std::fs::write(path, content)
+            .map_err(|err| format!("Could not write {}: {}", path.display(), err))
+    };
+    write(&module, &module_source)?;
+    write(&input, "")?;
+    write(&example, "")?;
+    write(&days_mod, &registry)?;
+
+    Ok(vec![module, input, example, days_mod])
+}
+
+/// Adds `pub mod dayN;` and `&dayN::DayN` to the source of `days/mod.rs`, both in day order.
+fn register(source: &str, day: u8) -> Result<String, String> {
+    let module_line = format!("pub mod day{};", day);
+    if source.lines().any(|line| line.trim() == module_line) {
+        return Err(format!("Day {} is already registered", day));
+    }
+
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+
+    // Module declarations are sorted by name like rustfmt does.
+    let module_name = |line: &str| {
+        line.strip_prefix("pub mod ")
+            .and_then(|name| name.strip_suffix(';'))
+            .map(String::from)
+    };
+    let name = format!("day{}", day);
+    let position = lines
+        .iter()
+        .position(|line| module_name(line).is_some_and(|other| other > name))
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|line| module_name(line).is_some())
+                .map(|index| index + 1)
+        })
+        .ok_or("Could not find the module declarations")?;
+    lines.insert(position, module_line);
+
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with(REGISTRY_START))
+        .ok_or("Could not find the DAYS registry")?;
+    let end = (start..lines.len())
+        .find(|index| lines[*index].trim_end().ends_with("];"))
+        .ok_or("Could not find the end of the DAYS registry")?;
+
+    let entries = lines[start..=end].join(" ");
+    let entries = entries
+        .trim_start_matches(REGISTRY_START)
+        .trim_end()
+        .trim_end_matches("];");
+    let mut entries: Vec<(u8, String)> = entries
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| (registry_day(entry), entry.to_string()))
+        .collect();
+    entries.push((day, format!("&day{}::Day{}", day, day)));
+    entries.sort();
+
+    let entries: Vec<String> = entries.into_iter().map(|(_, entry)| entry).collect();
+    let single_line = format!("{}{}];", REGISTRY_START, entries.join(", "));
+    let registry = if single_line.len() <= MAX_WIDTH {
+        vec![single_line]
+    } else {
+        std::iter::once(REGISTRY_START.to_string())
+            .chain(entries.iter().map(|entry| format!("    {},", entry)))
+            .chain(std::iter::once("];".to_string()))
+            .collect()
+    };
+    lines.splice(start..=end, registry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Day number of a registry entry like `&day12::Day12`.
+fn registry_day(entry: &str) -> u8 {
+    entry
+        .trim_start_matches("&day")
+        .split("::")
+        .next()
+        .and_then(|number| number.parse().ok())
+        .unwrap_or(u8::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD_RS: &str = "use crate::days::error::Error;
+
+pub mod answers;
+pub mod day1;
+pub mod day2;
+pub mod error;
+
+/// Every implemented day, in day order.
+pub static DAYS: &[&dyn Day] = &[&day1::Day1, &day2::Day2];
+";
+
+    #[test]
+    fn it_registers_new_days() {
+        let result = register(MOD_RS, 3).unwrap();
+
+        assert!(result.contains("pub mod day2;\npub mod day3;\npub mod error;"));
+        assert!(result.contains("&[&day1::Day1, &day2::Day2, &day3::Day3];"));
+    }
+
+    #[test]
+    fn it_registers_days_in_order() {
+        let result = register(&register(MOD_RS, 12).unwrap(), 10).unwrap();
+
+        assert!(result.contains("pub mod day1;\npub mod day10;\npub mod day12;\npub mod day2;"));
+        assert!(result.contains("&[&day1::Day1, &day2::Day2, &day10::Day10, &day12::Day12];"));
+    }
+
+    #[test]
+    fn it_wraps_long_registries() {
+        let result = (3..=9).try_fold(MOD_RS.to_string(), |acc, day| register(&acc, day));
+        let result = register(&result.unwrap(), 10).unwrap();
+
+        assert!(result.contains("= &[\n    &day1::Day1,\n"));
+        assert!(result.contains("    &day10::Day10,\n];\n"));
+        assert!(register(&result, 11)
+            .unwrap()
+            .contains("    &day11::Day11,\n];\n"));
+    }
+
+    #[test]
+    fn it_refuses_registered_days() {
+        assert!(register(MOD_RS, 2).is_err());
+    }
+
+    #[test]
+    fn it_refuses_to_overwrite_existing_days() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+        assert!(new_day(root, 1).is_err());
+    }
+
+    #[test]
+    fn it_needs_the_crate_directory() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+
+        assert!(new_day(&root, 3).is_err());
+        assert!(!root.join("src/days/day3.rs").exists());
+    }
+}
@@ -0,0 +1,86 @@
+//! Scaffolds a day in a copy of the crate and builds it from scratch, which takes a while. Run
+//! with `cargo test --test new_day -- --ignored`.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// A copy of the sources of the crate in the temporary directory, removed again on drop.
+struct CrateCopy {
+    root: PathBuf,
+}
+
+impl CrateCopy {
+    fn new() -> CrateCopy {
+        let from = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let copy = CrateCopy {
+            root: std::env::temp_dir()
+                .join(format!("advent_of_code_new_day_{}", std::process::id())),
+        };
+        for name in ["Cargo.toml", "Cargo.lock", "benches", "inputs", "src"] {
+            if from.join(name).exists() {
+                copy_all(&from.join(name), &copy.root.join(name));
+            }
+        }
+        copy
+    }
+
+    fn cargo(&self, args: &[&str]) -> Output {
+        let output = Command::new(env!("CARGO"))
+            .args(args)
+            .current_dir(&self.root)
+            .env("CARGO_TARGET_DIR", self.root.join("target"))
+            .output()
+            .unwrap();
+
+        assert!(
+            output.status.success() || args.contains(&"run"),
+            "cargo {} failed:\n{}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        output
+    }
+}
+
+impl Drop for CrateCopy {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.root);
+    }
+}
+
+fn copy_all(from: &Path, to: &Path) {
+    if from.is_dir() {
+        for entry in std::fs::read_dir(from).unwrap() {
+            let entry = entry.unwrap();
+            copy_all(&entry.path(), &to.join(entry.file_name()));
+        }
+    } else {
+        std::fs::create_dir_all(to.parent().unwrap()).unwrap();
+        std::fs::copy(from, to).unwrap();
+    }
+}
+
+#[test]
+#[ignore]
+fn new_days_build_and_pass_the_tests() {
+    let copy = CrateCopy::new();
+
+    let scaffold = Command::new(env!("CARGO_BIN_EXE_advent_of_code"))
+        .args(["new-day", "3", "--root"])
+        .arg(&copy.root)
+        .output()
+        .unwrap();
+    assert!(
+        scaffold.status.success(),
+        "{}",
+        String::from_utf8_lossy(&scaffold.stderr)
+    );
+
+    copy.cargo(&["fmt", "--check"]);
+    copy.cargo(&["build", "--offline"]);
+    copy.cargo(&["test", "--offline"]);
+
+    let run = copy.cargo(&["run", "--offline", "--", "--example", "--day", "3"]);
+    let stderr = String::from_utf8_lossy(&run.stderr);
+    assert!(stderr.contains("not implemented yet"), "{}", stderr);
+}